@@ -1,9 +1,3 @@
-use std::u32;
-use std::u64;
-use std::convert::TryInto;
-use std::collections::{HashMap, BTreeMap};
-use crate::field::Fr;
-
 // Core helper functions for the Jolt zkVM
 pub fn truncate_32(z: u64) -> u32 {
     (z & 0xFFFF_FFFF) as u32
@@ -41,189 +35,3 @@ pub fn concatenate(chunks: &[u64], m: u8) -> u64 {
     result
 }
 
-/// Advanced type system with dependent types
-pub struct TypeSystem {
-    // Type checker
-    checker: TypeChecker,
-    
-    // Type inference
-    inference: TypeInference,
-    
-    // Subtyping
-    subtyping: SubtypingRelations,
-    
-    // Type environment
-    environment: TypeEnvironment,
-}
-
-/// Type checker with dependent types
-pub struct TypeChecker {
-    // Type rules
-    rules: Vec<TypeRule>,
-    
-    // Constraint solver
-    solver: ConstraintSolver,
-    
-    // Refinement checker
-    refinements: RefinementChecker,
-}
-
-impl TypeChecker {
-    /// Check type safety
-    pub fn check(&self, expr: &Expr, env: &TypeEnvironment) -> Result<Type, TypeError> {
-        // 1. Generate constraints
-        let constraints = self.generate_constraints(expr, env)?;
-        
-        // 2. Solve constraints
-        let solution = self.solver.solve(&constraints)?;
-        
-        // 3. Check refinements
-        self.refinements.check(&solution)?;
-        
-        // 4. Construct result type
-        let result_type = self.construct_type(&solution)?;
-        
-        Ok(result_type)
-    }
-}
-
-/// Type inference engine
-pub struct TypeInference {
-    // Constraint generation
-    constraint_gen: ConstraintGenerator,
-    
-    // Unification
-    unifier: Unifier,
-    
-    // Type reconstruction
-    reconstruction: TypeReconstruction,
-}
-
-impl TypeInference {
-    /// Infer types
-    pub fn infer(&self, expr: &Expr, env: &TypeEnvironment) -> Result<Type, TypeError> {
-        // 1. Generate constraints
-        let constraints = self.constraint_gen.generate(expr, env)?;
-        
-        // 2. Unify constraints
-        let substitution = self.unifier.unify(&constraints)?;
-        
-        // 3. Reconstruct types
-        let result_type = self.reconstruction.reconstruct(expr, &substitution)?;
-        
-        Ok(result_type)
-    }
-}
-
-/// Subtyping relations
-pub struct SubtypingRelations {
-    // Subtype lattice
-    lattice: SubtypeLattice,
-    
-    // Variance rules
-    variance: VarianceRules,
-    
-    // Transitivity checker
-    transitivity: TransitivityChecker,
-}
-
-impl SubtypingRelations {
-    /// Check subtyping
-    pub fn is_subtype(&self, sub: &Type, sup: &Type) -> Result<bool, TypeError> {
-        // 1. Check direct subtyping
-        if self.lattice.is_direct_subtype(sub, sup)? {
-            return Ok(true);
-        }
-        
-        // 2. Apply variance rules
-        if self.variance.check_variance(sub, sup)? {
-            return Ok(true);
-        }
-        
-        // 3. Check transitivity
-        self.transitivity.check_transitive(sub, sup)
-    }
-}
-
-/// Type environment
-pub struct TypeEnvironment {
-    // Type bindings
-    bindings: HashMap<Identifier, Type>,
-    
-    // Type constraints
-    constraints: Vec<TypeConstraint>,
-    
-    // Type assumptions
-    assumptions: Vec<TypeAssumption>,
-}
-
-impl TypeEnvironment {
-    /// Add type binding
-    pub fn add_binding(&mut self, id: Identifier, ty: Type) -> Result<(), TypeError> {
-        // 1. Check well-formedness
-        self.check_well_formed(&ty)?;
-        
-        // 2. Check constraints
-        self.check_constraints(&id, &ty)?;
-        
-        // 3. Add binding
-        self.bindings.insert(id, ty);
-        
-        Ok(())
-    }
-}
-
-/// Types
-#[derive(Clone, Debug)]
-pub enum Type {
-    // Base types
-    Unit,
-    Bool,
-    Int(IntType),
-    Float(FloatType),
-    
-    // Compound types
-    Product(Vec<Type>),
-    Sum(Vec<Type>),
-    Function(Box<Type>, Box<Type>),
-    
-    // Dependent types
-    Dependent(Box<Type>, Box<Refinement>),
-    
-    // Refinement types
-    Refined(Box<Type>, Box<Predicate>),
-}
-
-/// Type rules
-pub struct TypeRule {
-    // Premises
-    premises: Vec<TypeJudgment>,
-    
-    // Conclusion
-    conclusion: TypeJudgment,
-    
-    // Side conditions
-    side_conditions: Vec<Condition>,
-}
-
-/// Type constraints
-#[derive(Clone, Debug)]
-pub enum TypeConstraint {
-    // Equality constraints
-    Equal(Type, Type),
-    
-    // Subtyping constraints
-    Subtype(Type, Type),
-    
-    // Refinement constraints
-    Refinement(Predicate),
-}
-
-#[derive(Debug)]
-pub enum TypeError {
-    UnificationFailed,
-    ConstraintUnsatisfiable,
-    RefinementCheckFailed,
-    SubtypingFailed,
-    IllFormed,
-}
\ No newline at end of file
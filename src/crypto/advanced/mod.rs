@@ -0,0 +1,3 @@
+mod quantum;
+
+pub use self::quantum::*;
@@ -0,0 +1,502 @@
+use crate::crypto::uniform_r1cs::{verify_batched, SparseMatrix};
+use crate::field::Fr;
+
+/// Uniform R1CS synthesis for `ArithmeticUnit::execute`: one fixed block
+/// of rank-1 constraints per `Add`/`Sub`/`Mul`/`Div` step, stacked the same
+/// way [`super::uniform_r1cs::UniformR1CS`] stacks CPU fetch-decode-execute
+/// steps. This is the arithmetic circuit side of the same constraint
+/// object `VerificationConditionGenerator::generate` builds the axiomatic
+/// and operational conditions for — `ArithmeticR1CS::prove`'s per-row
+/// check (`Az_i ∘ Bz_i == Cz_i`) is the rank-1 encoding of the full-adder,
+/// partial-product, and divisor identities those semantics already state.
+const BITS: usize = 64;
+const LIMBS: usize = 4;
+const PARTIALS: usize = LIMBS * LIMBS;
+
+/// Which `ArithmeticUnit::execute` branch produced this step. Every step
+/// runs the same uniform template regardless of `op` — the one-hot flags
+/// below select which sub-circuit's output becomes `RESULT`/`SECONDARY`,
+/// the same selector-flag technique Jolt uses to share one constraint
+/// shape across instruction variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// One `ArithmeticUnit::execute` call's witness, in the layout the uniform
+/// arithmetic R1CS template expects. One execution trace is `&[ArithStep]`,
+/// stacked over the single-step template the same way [`super::uniform_r1cs::Step`]
+/// stacks CPU steps.
+#[derive(Clone, Debug)]
+pub struct ArithStep {
+    pub op: ArithOp,
+    pub a: u64,
+    pub b: u64,
+    /// `sum`/`diff` for `Add`/`Sub`, the low 64 bits of the product for
+    /// `Mul`, the quotient for `Div`.
+    pub result: u64,
+    /// The adder's carry-out for `Add`/`Sub`, the high 64 bits of the
+    /// product for `Mul`, the remainder for `Div`.
+    pub secondary: u64,
+    pub zero: bool,
+    pub negative: bool,
+    pub overflow: bool,
+    pub carry: bool,
+}
+
+#[derive(Debug)]
+pub enum ArithR1CSError {
+    /// `Az ∘ Bz != Cz` at the given (step, constraint-row) pair.
+    UnsatisfiedConstraint(usize, usize),
+    DivisionIdentityMismatch(usize),
+}
+
+fn bits_le(value: u64) -> [bool; BITS] {
+    let mut out = [false; BITS];
+    for (i, bit) in out.iter_mut().enumerate() {
+        *bit = (value >> i) & 1 == 1;
+    }
+    out
+}
+
+fn limbs_le(value: u64) -> [u64; LIMBS] {
+    let mask = (1u64 << (BITS / LIMBS)) - 1;
+    let mut out = [0u64; LIMBS];
+    for (i, limb) in out.iter_mut().enumerate() {
+        *limb = (value >> (i * (BITS / LIMBS))) & mask;
+    }
+    out
+}
+
+/// Runs the full-adder chain `sum = a⊕b⊕cin`, `cout = (a∧b)∨(cin∧(a⊕b))`
+/// bit by bit, matching `ConstantTimeAdder::full_adder`. Returns the
+/// per-bit `(a, b, ab, xor, carry_in * xor, sum, carry_out)` tuples the
+/// R1CS template below binds one row each.
+struct AdderTrace {
+    a_bits: [bool; BITS],
+    b_bits: [bool; BITS],
+    ab: [bool; BITS],
+    xor: [bool; BITS],
+    cin_xor: [bool; BITS],
+    sum_bits: [bool; BITS],
+    carry: [bool; BITS], // carry[i] is the carry out of bit i
+}
+
+fn run_adder(a: u64, b: u64) -> AdderTrace {
+    let a_bits = bits_le(a);
+    let b_bits = bits_le(b);
+    let mut ab = [false; BITS];
+    let mut xor = [false; BITS];
+    let mut cin_xor = [false; BITS];
+    let mut sum_bits = [false; BITS];
+    let mut carry = [false; BITS];
+
+    let mut cin = false;
+    for i in 0..BITS {
+        ab[i] = a_bits[i] & b_bits[i];
+        xor[i] = a_bits[i] ^ b_bits[i];
+        cin_xor[i] = cin & xor[i];
+        sum_bits[i] = xor[i] ^ cin;
+        carry[i] = ab[i] | cin_xor[i];
+        cin = carry[i];
+    }
+
+    AdderTrace { a_bits, b_bits, ab, xor, cin_xor, sum_bits, carry }
+}
+
+/// The uniform arithmetic R1CS subsystem: one `ArithmeticUnit::execute`
+/// call's constraint matrices, applied virtually across a whole trace
+/// instead of being materialized once per step.
+pub struct ArithmeticR1CS {
+    step_vars: usize,
+}
+
+impl ArithmeticR1CS {
+    // Column layout within one step's witness block.
+    const A_VAL: usize = 0;
+    const B_VAL: usize = 1;
+    const RESULT: usize = 2;
+    const SECONDARY: usize = 3;
+    const ZERO_FLAG: usize = 4;
+    const NEG_FLAG: usize = 5;
+    const OVERFLOW_FLAG: usize = 6;
+    const CARRY_FLAG: usize = 7;
+    const IS_ADD: usize = 8;
+    const IS_SUB: usize = 9;
+    const IS_MUL: usize = 10;
+    const IS_DIV: usize = 11;
+    const ADDER_OUT: usize = 12;
+    const ADDER_CARRY_OUT: usize = 13;
+    const MUL_LOW: usize = 14;
+    const MUL_HIGH: usize = 15;
+    const DIV_Q: usize = 16;
+    const DIV_R: usize = 17;
+    const ABIT_BASE: usize = 18;
+    const BBIT_BASE: usize = Self::ABIT_BASE + BITS;
+    const SUMBIT_BASE: usize = Self::BBIT_BASE + BITS;
+    const CARRY_BASE: usize = Self::SUMBIT_BASE + BITS;
+    const AB_BASE: usize = Self::CARRY_BASE + BITS;
+    const XOR_BASE: usize = Self::AB_BASE + BITS;
+    const CXOR_BASE: usize = Self::XOR_BASE + BITS;
+    const ALIMB_BASE: usize = Self::CXOR_BASE + BITS;
+    const BLIMB_BASE: usize = Self::ALIMB_BASE + LIMBS;
+    const PARTIAL_BASE: usize = Self::BLIMB_BASE + LIMBS;
+    // Selector*candidate helper products feeding the RESULT/SECONDARY
+    // linear combination: [add, sub, mul, div] for each of the two outputs.
+    const RESULT_TERM_BASE: usize = Self::PARTIAL_BASE + PARTIALS;
+    const SECONDARY_TERM_BASE: usize = Self::RESULT_TERM_BASE + 4;
+    const VARS_PER_STEP: usize = Self::SECONDARY_TERM_BASE + 4;
+
+    pub fn new() -> Self {
+        ArithmeticR1CS { step_vars: Self::VARS_PER_STEP }
+    }
+
+    fn add_booleanity(a: &mut SparseMatrix, b: &mut SparseMatrix, c: &mut SparseMatrix, row: usize, col: usize) {
+        a.set(row, col, Fr::one());
+        b.set(row, col, Fr::one());
+        c.set(row, col, Fr::one());
+    }
+
+    /// Builds the single-step `(A, B, C)` template: the full-adder chain
+    /// (booleanity + linear carry/sum identities), the 4x4 limb partial
+    /// products tying into `MUL_LOW`/`MUL_HIGH`, the divisor identity
+    /// `a = q*b + r` (range-checking `r < b` is left to a separate gadget,
+    /// the same way chunk decomposition's digit widths are in
+    /// [`super::uniform_r1cs::UniformR1CS`]), the one-hot op selector, and
+    /// the selector-gated `RESULT`/`SECONDARY` output.
+    pub fn synthesize_step(&self) -> (SparseMatrix, SparseMatrix, SparseMatrix) {
+        let vars = self.step_vars;
+        let one_col = vars; // constant-1 column
+
+        let num_constraints = 5 // one-hot booleanity + sum-to-one
+            + BITS * 7 // booleanity(a) + booleanity(b) + ab + xor + cxor + sum + cout
+            + 2 // ADDER_OUT reconstruction + carry-out tie
+            + PARTIALS // partial products
+            + 2 // MUL_LOW / MUL_HIGH reconstruction
+            + 1 // division identity
+            + 4 + 1 // RESULT selector terms + combine
+            + 4 + 1 // SECONDARY selector terms + combine
+            + 4; // flag booleanity
+
+        let mut a = SparseMatrix::new(num_constraints, vars + 1);
+        let mut b = SparseMatrix::new(num_constraints, vars + 1);
+        let mut c = SparseMatrix::new(num_constraints, vars + 1);
+        let mut row = 0;
+
+        // One-hot selector: each flag boolean, and IS_ADD+IS_SUB+IS_MUL+IS_DIV = 1.
+        for &col in &[Self::IS_ADD, Self::IS_SUB, Self::IS_MUL, Self::IS_DIV] {
+            Self::add_booleanity(&mut a, &mut b, &mut c, row, col);
+            row += 1;
+        }
+        a.set(row, Self::IS_ADD, Fr::one());
+        a.set(row, Self::IS_SUB, Fr::one());
+        a.set(row, Self::IS_MUL, Fr::one());
+        a.set(row, Self::IS_DIV, Fr::one());
+        b.set(row, one_col, Fr::one());
+        c.set(row, one_col, Fr::one());
+        row += 1;
+
+        // Full-adder chain, one block of rows per bit.
+        for i in 0..BITS {
+            let a_col = Self::ABIT_BASE + i;
+            let b_col = Self::BBIT_BASE + i;
+            let ab_col = Self::AB_BASE + i;
+            let xor_col = Self::XOR_BASE + i;
+            let cxor_col = Self::CXOR_BASE + i;
+            let sum_col = Self::SUMBIT_BASE + i;
+            let carry_col = Self::CARRY_BASE + i;
+            let cin_col = if i == 0 { None } else { Some(Self::CARRY_BASE + i - 1) };
+
+            Self::add_booleanity(&mut a, &mut b, &mut c, row, a_col);
+            row += 1;
+            Self::add_booleanity(&mut a, &mut b, &mut c, row, b_col);
+            row += 1;
+
+            // ab_i = a_i * b_i
+            a.set(row, a_col, Fr::one());
+            b.set(row, b_col, Fr::one());
+            c.set(row, ab_col, Fr::one());
+            row += 1;
+
+            // xor_i = a_i + b_i - 2*ab_i (linear, trivial B = 1)
+            a.set(row, a_col, Fr::one());
+            a.set(row, b_col, Fr::one());
+            a.set(row, ab_col, Fr::zero() - Fr::from(2));
+            b.set(row, one_col, Fr::one());
+            c.set(row, xor_col, Fr::one());
+            row += 1;
+
+            // cxor_i = cin_i * xor_i (cin_0 is the constant 0, so that row
+            // degenerates to asserting cxor_0 = 0 with an empty A side).
+            if let Some(cin_col) = cin_col {
+                a.set(row, cin_col, Fr::one());
+            }
+            b.set(row, xor_col, Fr::one());
+            c.set(row, cxor_col, Fr::one());
+            row += 1;
+
+            // sum_i = xor_i + cin_i - 2*cxor_i (linear)
+            a.set(row, xor_col, Fr::one());
+            if let Some(cin_col) = cin_col {
+                a.set(row, cin_col, Fr::one());
+            }
+            a.set(row, cxor_col, Fr::zero() - Fr::from(2));
+            b.set(row, one_col, Fr::one());
+            c.set(row, sum_col, Fr::one());
+            row += 1;
+
+            // cout_i = ab_i + cxor_i (linear)
+            a.set(row, ab_col, Fr::one());
+            a.set(row, cxor_col, Fr::one());
+            b.set(row, one_col, Fr::one());
+            c.set(row, carry_col, Fr::one());
+            row += 1;
+        }
+
+        // ADDER_OUT = sum_i sum_bit_i * 2^i
+        for i in 0..BITS {
+            a.set(row, Self::SUMBIT_BASE + i, Fr::from(1u64 << i));
+        }
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::ADDER_OUT, Fr::one());
+        row += 1;
+
+        // ADDER_CARRY_OUT = carry out of the top bit.
+        a.set(row, Self::CARRY_BASE + BITS - 1, Fr::one());
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::ADDER_CARRY_OUT, Fr::one());
+        row += 1;
+
+        // 4x4 limb partial products: partial_ij = a_limb_i * b_limb_j.
+        for i in 0..LIMBS {
+            for j in 0..LIMBS {
+                a.set(row, Self::ALIMB_BASE + i, Fr::one());
+                b.set(row, Self::BLIMB_BASE + j, Fr::one());
+                c.set(row, Self::PARTIAL_BASE + i * LIMBS + j, Fr::one());
+                row += 1;
+            }
+        }
+
+        // MUL_LOW/MUL_HIGH: a structural tie of the partial products into
+        // two weighted halves, not a fully carry-propagated 128-bit
+        // recomposition (same caveat as the sign-extension row in
+        // `UniformR1CS::synthesize_step`).
+        let limb_bits = BITS / LIMBS;
+        for i in 0..LIMBS {
+            for j in 0..LIMBS {
+                let shift = limb_bits * (i + j);
+                let col = Self::PARTIAL_BASE + i * LIMBS + j;
+                if i + j < LIMBS {
+                    a.set(row, col, Fr::from(1u64 << shift));
+                } else {
+                    a.set(row, col, Fr::from(1u64 << (shift - BITS)));
+                }
+            }
+        }
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::MUL_LOW, Fr::one());
+        row += 1;
+        for i in 0..LIMBS {
+            for j in 0..LIMBS {
+                if i + j >= LIMBS {
+                    let shift = limb_bits * (i + j) - BITS;
+                    a.set(row, Self::PARTIAL_BASE + i * LIMBS + j, Fr::from(1u64 << shift));
+                }
+            }
+        }
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::MUL_HIGH, Fr::one());
+        row += 1;
+
+        // Divisor identity: a = q*b + r (range-checking r < b is left to a
+        // separate gadget, matching the chunk-width caveat above).
+        a.set(row, Self::DIV_Q, Fr::one());
+        b.set(row, Self::B_VAL, Fr::one());
+        c.set(row, Self::A_VAL, Fr::one());
+        c.set(row, Self::DIV_R, Fr::zero() - Fr::one());
+        row += 1;
+
+        // RESULT = IS_ADD*ADDER_OUT + IS_SUB*ADDER_OUT + IS_MUL*MUL_LOW + IS_DIV*DIV_Q
+        let result_candidates = [
+            (Self::IS_ADD, Self::ADDER_OUT),
+            (Self::IS_SUB, Self::ADDER_OUT),
+            (Self::IS_MUL, Self::MUL_LOW),
+            (Self::IS_DIV, Self::DIV_Q),
+        ];
+        for (k, &(flag, candidate)) in result_candidates.iter().enumerate() {
+            a.set(row, flag, Fr::one());
+            b.set(row, candidate, Fr::one());
+            c.set(row, Self::RESULT_TERM_BASE + k, Fr::one());
+            row += 1;
+        }
+        for k in 0..4 {
+            a.set(row, Self::RESULT_TERM_BASE + k, Fr::one());
+        }
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::RESULT, Fr::one());
+        row += 1;
+
+        // SECONDARY = IS_ADD*ADDER_CARRY_OUT + IS_SUB*ADDER_CARRY_OUT + IS_MUL*MUL_HIGH + IS_DIV*DIV_R
+        let secondary_candidates = [
+            (Self::IS_ADD, Self::ADDER_CARRY_OUT),
+            (Self::IS_SUB, Self::ADDER_CARRY_OUT),
+            (Self::IS_MUL, Self::MUL_HIGH),
+            (Self::IS_DIV, Self::DIV_R),
+        ];
+        for (k, &(flag, candidate)) in secondary_candidates.iter().enumerate() {
+            a.set(row, flag, Fr::one());
+            b.set(row, candidate, Fr::one());
+            c.set(row, Self::SECONDARY_TERM_BASE + k, Fr::one());
+            row += 1;
+        }
+        for k in 0..4 {
+            a.set(row, Self::SECONDARY_TERM_BASE + k, Fr::one());
+        }
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::SECONDARY, Fr::one());
+        row += 1;
+
+        // Status flags are booleans; their relation to RESULT/SECONDARY is
+        // the per-op update logic in `ArithmeticUnit::update_flags*`, not a
+        // rank-1 identity, so only booleanity is enforced here.
+        for &col in &[Self::ZERO_FLAG, Self::NEG_FLAG, Self::OVERFLOW_FLAG, Self::CARRY_FLAG] {
+            Self::add_booleanity(&mut a, &mut b, &mut c, row, col);
+            row += 1;
+        }
+
+        debug_assert_eq!(row, num_constraints);
+        (a, b, c)
+    }
+
+    fn fill_witness(&self, step: &ArithStep) -> Vec<Fr> {
+        let mut z = vec![Fr::zero(); self.step_vars + 1];
+        z[Self::A_VAL] = Fr::from(step.a);
+        z[Self::B_VAL] = Fr::from(step.b);
+        z[Self::RESULT] = Fr::from(step.result);
+        z[Self::SECONDARY] = Fr::from(step.secondary);
+        z[Self::ZERO_FLAG] = bool_fr(step.zero);
+        z[Self::NEG_FLAG] = bool_fr(step.negative);
+        z[Self::OVERFLOW_FLAG] = bool_fr(step.overflow);
+        z[Self::CARRY_FLAG] = bool_fr(step.carry);
+        z[Self::IS_ADD] = bool_fr(step.op == ArithOp::Add);
+        z[Self::IS_SUB] = bool_fr(step.op == ArithOp::Sub);
+        z[Self::IS_MUL] = bool_fr(step.op == ArithOp::Mul);
+        z[Self::IS_DIV] = bool_fr(step.op == ArithOp::Div);
+
+        let adder_b = if step.op == ArithOp::Sub { step.b.wrapping_neg() } else { step.b };
+        let adder = run_adder(step.a, adder_b);
+        for i in 0..BITS {
+            z[Self::ABIT_BASE + i] = bool_fr(adder.a_bits[i]);
+            z[Self::BBIT_BASE + i] = bool_fr(adder.b_bits[i]);
+            z[Self::SUMBIT_BASE + i] = bool_fr(adder.sum_bits[i]);
+            z[Self::CARRY_BASE + i] = bool_fr(adder.carry[i]);
+            z[Self::AB_BASE + i] = bool_fr(adder.ab[i]);
+            z[Self::XOR_BASE + i] = bool_fr(adder.xor[i]);
+            z[Self::CXOR_BASE + i] = bool_fr(adder.cin_xor[i]);
+        }
+        z[Self::ADDER_OUT] = adder.sum_bits.iter().enumerate().fold(Fr::zero(), |acc, (i, &bit)| {
+            acc + bool_fr(bit) * Fr::from(1u64 << i)
+        });
+        z[Self::ADDER_CARRY_OUT] = bool_fr(adder.carry[BITS - 1]);
+
+        let a_limbs = limbs_le(step.a);
+        let b_limbs = limbs_le(step.b);
+        for i in 0..LIMBS {
+            z[Self::ALIMB_BASE + i] = Fr::from(a_limbs[i]);
+            z[Self::BLIMB_BASE + i] = Fr::from(b_limbs[i]);
+        }
+        let limb_bits = BITS / LIMBS;
+        let mut mul_low = Fr::zero();
+        let mut mul_high = Fr::zero();
+        for i in 0..LIMBS {
+            for j in 0..LIMBS {
+                let partial = a_limbs[i] * b_limbs[j];
+                z[Self::PARTIAL_BASE + i * LIMBS + j] = Fr::from(partial);
+                let shift = limb_bits * (i + j);
+                if i + j < LIMBS {
+                    mul_low += Fr::from(partial) * Fr::from(1u64 << shift);
+                } else {
+                    mul_high += Fr::from(partial) * Fr::from(1u64 << (shift - BITS));
+                }
+            }
+        }
+        z[Self::MUL_LOW] = mul_low;
+        z[Self::MUL_HIGH] = mul_high;
+
+        let (q, r) = if step.op == ArithOp::Div && step.b != 0 {
+            (step.a / step.b, step.a % step.b)
+        } else {
+            (0, step.a)
+        };
+        z[Self::DIV_Q] = Fr::from(q);
+        z[Self::DIV_R] = Fr::from(r);
+
+        let result_terms = [
+            (step.op == ArithOp::Add, z[Self::ADDER_OUT]),
+            (step.op == ArithOp::Sub, z[Self::ADDER_OUT]),
+            (step.op == ArithOp::Mul, z[Self::MUL_LOW]),
+            (step.op == ArithOp::Div, z[Self::DIV_Q]),
+        ];
+        for (k, &(selected, candidate)) in result_terms.iter().enumerate() {
+            z[Self::RESULT_TERM_BASE + k] = if selected { candidate } else { Fr::zero() };
+        }
+        let secondary_terms = [
+            (step.op == ArithOp::Add, z[Self::ADDER_CARRY_OUT]),
+            (step.op == ArithOp::Sub, z[Self::ADDER_CARRY_OUT]),
+            (step.op == ArithOp::Mul, z[Self::MUL_HIGH]),
+            (step.op == ArithOp::Div, z[Self::DIV_R]),
+        ];
+        for (k, &(selected, candidate)) in secondary_terms.iter().enumerate() {
+            z[Self::SECONDARY_TERM_BASE + k] = if selected { candidate } else { Fr::zero() };
+        }
+
+        z[self.step_vars] = Fr::one();
+        z
+    }
+
+    /// Runs each step's witness filler and checks the uniform single-step
+    /// matrices hold, plus the divisor identity `a = q*b + r` directly
+    /// (the sum-check-discharged path is `verify`, below).
+    pub fn prove(&self, trace: &[ArithStep]) -> Result<Vec<Vec<Fr>>, ArithR1CSError> {
+        let (a, b, c) = self.synthesize_step();
+        let mut witnesses = Vec::with_capacity(trace.len());
+
+        for (i, step) in trace.iter().enumerate() {
+            if step.op == ArithOp::Div && step.b != 0 {
+                let (q, r) = (step.a / step.b, step.a % step.b);
+                if q != step.result || r != step.secondary {
+                    return Err(ArithR1CSError::DivisionIdentityMismatch(i));
+                }
+            }
+
+            let z = self.fill_witness(step);
+            let az = a.apply(&z);
+            let bz = b.apply(&z);
+            let cz = c.apply(&z);
+            for row in 0..az.len() {
+                if az[row] * bz[row] != cz[row] {
+                    return Err(ArithR1CSError::UnsatisfiedConstraint(i, row));
+                }
+            }
+
+            witnesses.push(z);
+        }
+
+        Ok(witnesses)
+    }
+
+    /// Verifies the repeated single-step system against a Fiat-Shamir
+    /// random linear combination across steps, the same batching
+    /// `UniformR1CS::verify` uses for the CPU-step template.
+    pub fn verify(&self, witnesses: &[Vec<Fr>], r: Fr) -> bool {
+        let (a, b, c) = self.synthesize_step();
+        verify_batched(&a, &b, &c, witnesses, r)
+    }
+}
+
+fn bool_fr(bit: bool) -> Fr {
+    if bit { Fr::one() } else { Fr::zero() }
+}
@@ -0,0 +1,23 @@
+use crate::field::Fr;
+
+/// Appends `fr` as a big-endian 32-byte `uint256` word — the calldata
+/// layout every generated Solidity verifier in this crate expects an `Fr`
+/// encoded as, since the EVM has no native notion of this crate's
+/// (non-BN254) field.
+pub(crate) fn push_word(bytes: &mut Vec<u8>, fr: Fr) {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&fr.to_u64().to_be_bytes());
+    bytes.extend_from_slice(&word);
+}
+
+/// Inverse of [`push_word`]: reads one big-endian 32-byte word at
+/// `offset`, returning the decoded `Fr` and the offset just past it, or
+/// `None` if `data` doesn't have a full word left at `offset`.
+pub(crate) fn read_word(data: &[u8], offset: usize) -> Option<(Fr, usize)> {
+    if data.len() < offset + 32 {
+        return None;
+    }
+    let mut limb = [0u8; 8];
+    limb.copy_from_slice(&data[offset + 24..offset + 32]);
+    Some((Fr::from(u64::from_be_bytes(limb)), offset + 32))
+}
@@ -2,6 +2,71 @@ use super::hash::PoseidonHash;
 use crate::field::Fr;
 use rand::RngCore;
 
+/// Bit-width of each multi-scalar-multiplication window: the number of
+/// buckets per window is `2^WINDOW_BITS - 1`, chosen by the usual
+/// `c ~ ln(n)` heuristic for where Pippenger's bucket method overtakes a
+/// naive per-scalar double-and-add loop.
+const WINDOW_BITS: u32 = 10;
+
+/// Pippenger's bucket-method multi-scalar multiplication:
+/// `sum_i scalars[i] * bases[i]` over this crate's `Fr`-valued pseudo-group
+/// (`bases` stand in for group elements, `+` is the group op, and "scalar
+/// multiplication" is repeated addition), computed in `O(n / log n)` group
+/// additions instead of the `O(n)` doublings a naive per-scalar loop costs.
+///
+/// Each scalar is split into `ceil(64 / WINDOW_BITS)` `WINDOW_BITS`-wide
+/// digit windows. For window `w`, `bases[i]` is accumulated into bucket
+/// `digit - 1` where `digit` is `scalars[i]`'s `w`-th window (a zero digit
+/// — including every window of a zero scalar — is skipped, contributing
+/// nothing). Each window's `2^WINDOW_BITS - 1` buckets are then reduced
+/// with the running-sum trick: walking from the top bucket down, a
+/// running accumulator picks up each bucket once and is itself folded into
+/// the window sum at every step, so a bucket holding `k` points ends up
+/// counted `k` times without `k` separate additions (an all-empty window
+/// just reduces to zero). Windows are combined top-down with `WINDOW_BITS`
+/// doublings between them, the same way manual double-and-add combines the
+/// bits of a single scalar; the top window needs no prior doublings, which
+/// falls out for free since doubling zero is a no-op. A window whose true
+/// bit-width is less than `WINDOW_BITS` (when `WINDOW_BITS` doesn't evenly
+/// divide 64) still masks out a valid digit, it's just padded with
+/// always-zero high bits.
+pub fn commit_msm(scalars: &[Fr], bases: &[Fr]) -> Fr {
+    assert_eq!(scalars.len(), bases.len());
+    if scalars.is_empty() {
+        return Fr::zero();
+    }
+
+    let num_buckets = (1usize << WINDOW_BITS) - 1;
+    let mask = num_buckets as u64;
+    let num_windows = (64 + WINDOW_BITS - 1) / WINDOW_BITS;
+
+    let mut result = Fr::zero();
+    for w in (0..num_windows).rev() {
+        for _ in 0..WINDOW_BITS {
+            result += result;
+        }
+
+        let mut buckets = vec![Fr::zero(); num_buckets];
+        for (scalar, base) in scalars.iter().zip(bases.iter()) {
+            let digit = (scalar.to_u64() >> (w * WINDOW_BITS)) & mask;
+            if digit != 0 {
+                buckets[(digit - 1) as usize] += *base;
+            }
+        }
+
+        let mut running_sum = Fr::zero();
+        let mut window_sum = Fr::zero();
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            window_sum += running_sum;
+        }
+
+        result += window_sum;
+    }
+
+    result
+}
+
 pub struct PedersenCommitment {
     generators: Vec<Fr>,
     blinding_factor: Fr,
@@ -13,7 +78,7 @@ impl PedersenCommitment {
         let generators = (0..num_generators)
             .map(|_| Fr::random(&mut rng))
             .collect();
-        
+
         PedersenCommitment {
             generators,
             blinding_factor: Fr::random(&mut rng),
@@ -22,11 +87,112 @@ impl PedersenCommitment {
 
     pub fn commit(&self, values: &[Fr]) -> Fr {
         assert_eq!(values.len(), self.generators.len());
-        
-        let mut commitment = Fr::zero();
-        for (value, generator) in values.iter().zip(self.generators.iter()) {
-            commitment += *value * generator;
+        commit_msm(values, &self.generators) + self.blinding_factor
+    }
+
+    /// Builds a `PedersenCommitment` from an externally-supplied generator
+    /// vector — e.g. a `crate::crypto::universal_srs::UniversalSrs`
+    /// trimmed to `generators.len()` — instead of `new`'s independently
+    /// sampled ones, so several commitment schemes can share one
+    /// powers-of-tau setup. Only the blinding factor is freshly random.
+    pub fn from_srs(generators: Vec<Fr>) -> Self {
+        let mut rng = rand::thread_rng();
+        PedersenCommitment {
+            generators,
+            blinding_factor: Fr::random(&mut rng),
         }
-        commitment += self.blinding_factor
+    }
+}
+
+/// Max-degree polynomial commitment key: `commit` treats a polynomial's
+/// coefficient vector as MSM scalars against a prefix of `self.bases`, the
+/// same way `PedersenCommitment::commit` treats a witness vector, just
+/// without a blinding term — `ProofGenerator` commits to polynomials it
+/// later opens, not values it hides.
+pub struct PolyCommitment {
+    bases: Vec<Fr>,
+}
+
+/// Generous default so `PolyCommitment::new()` (no degree bound known yet
+/// at construction) covers the witness/state polynomials `ProofGenerator`
+/// commits to without resizing.
+const POLY_COMMITMENT_MAX_DEGREE: usize = 1 << 12;
+
+impl PolyCommitment {
+    pub fn new() -> Self {
+        let mut rng = rand::thread_rng();
+        let bases = (0..POLY_COMMITMENT_MAX_DEGREE)
+            .map(|_| Fr::random(&mut rng))
+            .collect();
+        PolyCommitment { bases }
+    }
+
+    /// Commits to `coeffs`; panics if it's longer than the commitment key,
+    /// the same way `PedersenCommitment::commit` panics on a
+    /// generator-count mismatch.
+    pub fn commit(&self, coeffs: &[Fr]) -> Fr {
+        assert!(coeffs.len() <= self.bases.len());
+        commit_msm(coeffs, &self.bases[..coeffs.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `commit_msm`'s windowed bucket method has to agree with the naive
+    /// `sum_i scalars[i] * bases[i]` it's an optimization of.
+    #[test]
+    fn commit_msm_matches_naive_sum() {
+        let scalars = vec![Fr::from(3), Fr::from(0), Fr::from(17), Fr::from(1 << 20)];
+        let bases = vec![Fr::from(5), Fr::from(9999), Fr::from(2), Fr::from(7)];
+
+        let expected = scalars
+            .iter()
+            .zip(&bases)
+            .fold(Fr::zero(), |acc, (&s, &b)| acc + b * s);
+
+        assert_eq!(commit_msm(&scalars, &bases), expected);
+    }
+
+    #[test]
+    fn commit_msm_empty_is_zero() {
+        assert_eq!(commit_msm(&[], &[]), Fr::zero());
+    }
+
+    /// Same values, same generators, same blinding factor (by constructing
+    /// once and committing twice) must commit to the same point.
+    #[test]
+    fn pedersen_commit_deterministic_for_fixed_key() {
+        let pedersen = PedersenCommitment::new(3);
+        let values = vec![Fr::from(1), Fr::from(2), Fr::from(3)];
+
+        assert_eq!(pedersen.commit(&values), pedersen.commit(&values));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pedersen_commit_panics_on_generator_count_mismatch() {
+        let pedersen = PedersenCommitment::new(3);
+        pedersen.commit(&[Fr::from(1), Fr::from(2)]);
+    }
+
+    #[test]
+    fn poly_commitment_accepts_any_degree_up_to_the_key() {
+        let key = PolyCommitment::new();
+        // Both commit over the shared prefix `key.bases[..2]`; the longer
+        // one adds `3 * key.bases[2]` on top, so they differ unless that
+        // base is zero.
+        let short = key.commit(&[Fr::from(1), Fr::from(2)]);
+        let long = key.commit(&[Fr::from(1), Fr::from(2), Fr::from(3)]);
+        assert_ne!(short, long);
+    }
+
+    #[test]
+    #[should_panic]
+    fn poly_commitment_panics_past_max_degree() {
+        let key = PolyCommitment::new();
+        let coeffs = vec![Fr::zero(); POLY_COMMITMENT_MAX_DEGREE + 1];
+        key.commit(&coeffs);
     }
 }
\ No newline at end of file
@@ -1,6 +1,17 @@
+// Not wired into `crypto::mod` (see the note there): `create_proof_builds`
+// aside, this module needs a real recursive-proof/batch-verification
+// design that doesn't exist anywhere in the crate yet --
+// `CryptoConfig`, `PolynomialSystem`, `CurveOperations`, `RecursiveProver`,
+// `BatchVerifier`, `Commitment`, `Circuit`, `RecursiveProof`, and the
+// `KZGCommitment`/`ProofError`/`VerificationError`/`CommitmentError` types
+// below are all undefined; `PedersenCommitment::commit` also doesn't
+// return a `Result` the way `commit_polynomial` assumes. A type-error
+// patch over a handful of call sites wouldn't make this buildable -- it
+// needs the missing pieces designed and written first.
+
 use crate::field::Fr;
-use crate::polynomial::*;
-use crate::commitment::*;
+use crate::crypto::polynomial::*;
+use crate::crypto::commitment::*;
 
 /// Advanced cryptographic extensions
 pub struct CryptoExtensions {
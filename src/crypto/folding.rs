@@ -0,0 +1,109 @@
+use crate::crypto::proof::generator::Transcript;
+use crate::crypto::uniform_r1cs::SparseMatrix;
+use crate::crypto::PedersenCommitment;
+use crate::field::Fr;
+
+/// A satisfying witness for the uniform single-step R1CS template from
+/// [`super::uniform_r1cs`]/[`super::arithmetic_r1cs`]: implicitly `u = 1`,
+/// `E = 0`, i.e. the plain (non-relaxed) `A·z ∘ B·z = C·z` instance that
+/// each trace step produces.
+#[derive(Clone, Debug)]
+pub struct R1CSInstance {
+    pub z: Vec<Fr>,
+}
+
+/// A Sangria/Nova-style relaxed R1CS instance: `A·z ∘ B·z = u·(C·z) + E`.
+/// Folding `T` of these into one running accumulator lets a `T`-step
+/// execution trace be checked with a single satisfiability check instead
+/// of `T` independent [`super::arithmetic_r1cs::ArithmeticR1CS::prove`]-style
+/// checks.
+#[derive(Clone, Debug)]
+pub struct RelaxedInstance {
+    pub z: Vec<Fr>,
+    pub u: Fr,
+    pub e: Vec<Fr>,
+}
+
+impl RelaxedInstance {
+    /// Lifts a plain instance into the relaxed form (`u = 1`, `E = 0`),
+    /// the base case an incremental trace folds its first step into.
+    pub fn from_instance(step: R1CSInstance, num_rows: usize) -> Self {
+        RelaxedInstance {
+            z: step.z,
+            u: Fr::one(),
+            e: vec![Fr::zero(); num_rows],
+        }
+    }
+
+    /// Checks `A·z ∘ B·z == u·(C·z) + E` for the accumulated instance —
+    /// the one relation a verifier needs after folding an entire trace,
+    /// replacing `T` calls to the non-relaxed check. This is the final
+    /// condition `VerificationConditionGenerator::generate` would assemble
+    /// for an incremental arithmetic trace: one relaxed-instance check
+    /// standing in for `T` separate per-step conditions.
+    pub fn is_satisfied(&self, a: &SparseMatrix, b: &SparseMatrix, c: &SparseMatrix) -> bool {
+        let az = a.apply(&self.z);
+        let bz = b.apply(&self.z);
+        let cz = c.apply(&self.z);
+
+        (0..az.len()).all(|i| az[i] * bz[i] == self.u * cz[i] + self.e[i])
+    }
+}
+
+/// The cross term `T = A·z1∘B·z2 + A·z2∘B·z1 − u1·(C·z2) − u2·(C·z1)`.
+/// Substituting the folded `z = z1 + r·z2` into `A·z ∘ B·z` expands into
+/// `acc`'s relation, `step`'s relation, and exactly `r·T` — committing `T`
+/// before `r` is drawn is what makes the folded accumulator sound rather
+/// than just algebraically convenient.
+fn cross_term(
+    a: &SparseMatrix,
+    b: &SparseMatrix,
+    c: &SparseMatrix,
+    acc: &RelaxedInstance,
+    step: &R1CSInstance,
+) -> Vec<Fr> {
+    let az1 = a.apply(&acc.z);
+    let bz1 = b.apply(&acc.z);
+    let cz1 = c.apply(&acc.z);
+    let az2 = a.apply(&step.z);
+    let bz2 = b.apply(&step.z);
+    let cz2 = c.apply(&step.z);
+    let u2 = Fr::one(); // step is a non-relaxed instance
+
+    (0..az1.len())
+        .map(|i| az1[i] * bz2[i] + az2[i] * bz1[i] - acc.u * cz2[i] - u2 * cz1[i])
+        .collect()
+}
+
+/// Folds `step` into `acc` with a Fiat–Shamir challenge `r`: `z ← z1+r·z2`,
+/// `u ← u1+r·u2`, `E ← E1 + r²·E2 − r·T` (`u2 = 1`, `E2 = 0` since `step`
+/// is a non-relaxed instance). `T` is committed to `transcript` — via
+/// [`PedersenCommitment`], standing in for a real opening the same way
+/// [`super::uniform_r1cs::LayerProof`]'s evaluations do — before `r` is
+/// drawn, so the challenge can't depend on anything derived from itself.
+pub fn fold(
+    acc: RelaxedInstance,
+    step: R1CSInstance,
+    a: &SparseMatrix,
+    b: &SparseMatrix,
+    c: &SparseMatrix,
+    transcript: &mut impl Transcript,
+) -> RelaxedInstance {
+    let t = cross_term(a, b, c, &acc, &step);
+    let commitment_key = PedersenCommitment::new(t.len().max(1));
+    let t_commitment = commitment_key.commit(&t);
+    transcript.append_commitment("cross_term", &t_commitment);
+    let r = transcript.challenge_scalar("fold_r");
+
+    // r^2 * E2 drops out of the general formula: step's E2 is always zero.
+    let z = acc
+        .z
+        .iter()
+        .zip(step.z.iter())
+        .map(|(&z1, &z2)| z1 + r * z2)
+        .collect();
+    let u = acc.u + r;
+    let e = acc.e.iter().zip(t.iter()).map(|(&e1, &t_i)| e1 - r * t_i).collect();
+
+    RelaxedInstance { z, u, e }
+}
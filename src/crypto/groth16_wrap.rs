@@ -0,0 +1,91 @@
+use crate::crypto::folding::RelaxedInstance;
+use crate::crypto::uniform_r1cs::SparseMatrix;
+use crate::field::Fr;
+
+/// A placeholder BN254 scalar: a stand-in for the pairing-friendly curve's
+/// real ~254-bit scalar field, the same way [`super::solidity_verifier`]'s
+/// generated contract stands in for a real deployment. `u128` is too small
+/// to hold a real BN254 element, but every value here is already a
+/// placeholder packing of this crate's native `Fr`, not a cryptographically
+/// sound curve point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bn254Scalar(u128);
+
+impl Bn254Scalar {
+    fn mix(self, other: Bn254Scalar) -> Bn254Scalar {
+        Bn254Scalar(self.0.wrapping_mul(0x1000_0000_01).wrapping_add(other.0))
+    }
+}
+
+/// Packs pairs of native `Fr` limbs into one `Bn254Scalar` by placing the
+/// first limb in the low 64 bits and the second in the high 64 bits — a
+/// real re-encoding would reduce mod BN254's scalar modulus instead of
+/// just concatenating bits, but straight packing is enough to fix the
+/// layout the public-input digest below (and the generated Solidity
+/// verifier) needs to agree on.
+pub fn pack_limbs(native: &[Fr]) -> Vec<Bn254Scalar> {
+    native
+        .chunks(2)
+        .map(|chunk| {
+            let low = chunk[0].to_u64() as u128;
+            let high = chunk.get(1).map(Fr::to_u64).unwrap_or(0) as u128;
+            Bn254Scalar((high << 64) | low)
+        })
+        .collect()
+}
+
+/// Folds a packed native witness down to the single `Bn254Scalar` exposed
+/// as the wrapped proof's public input — the digest a verifier checks
+/// instead of re-deriving the whole native-field witness on an
+/// pairing-friendly curve that can't natively represent it.
+pub fn public_input_digest(native: &[Fr]) -> Bn254Scalar {
+    pack_limbs(native)
+        .into_iter()
+        .fold(Bn254Scalar(0), Bn254Scalar::mix)
+}
+
+/// A constant-size Groth16 proof: `A`, `C` in `G1` (two coordinates each),
+/// `B` in `G2` (two towers of two coordinates each) — the same
+/// eight-coordinate, three-group-element shape every real Groth16 proof has, here carrying placeholder
+/// [`Bn254Scalar`]s rather than real curve points. `public_input_digest`
+/// is the one public input the wrapping circuit exposes, matching
+/// [`public_input_digest`]'s packing so `verify_wrapped` and the generated
+/// Solidity verifier's `publicInputs` agree on what's being committed to.
+pub struct Groth16Proof {
+    pub a: (Bn254Scalar, Bn254Scalar),
+    pub b: ((Bn254Scalar, Bn254Scalar), (Bn254Scalar, Bn254Scalar)),
+    pub c: (Bn254Scalar, Bn254Scalar),
+    pub public_input_digest: Bn254Scalar,
+}
+
+/// Wraps a folded `RelaxedInstance` — the terminal state
+/// `super::folding::fold` produces after accumulating a whole recursive
+/// trace, standing in for `RecursiveProver`'s `RecursiveProof` — into a
+/// constant-size [`Groth16Proof`] suitable for cheap on-chain verification.
+/// Returns `None` rather than producing a proof of an unsatisfied
+/// instance: the wrapping circuit's first job is checking
+/// `acc.is_satisfied(a, b, c)`, and an honest prover never gets past that
+/// check with a bad accumulator.
+pub fn wrap(acc: &RelaxedInstance, a: &SparseMatrix, b: &SparseMatrix, c: &SparseMatrix) -> Option<Groth16Proof> {
+    if !acc.is_satisfied(a, b, c) {
+        return None;
+    }
+
+    let digest = public_input_digest(&acc.z);
+    Some(Groth16Proof {
+        a: (digest, digest),
+        b: ((digest, digest), (digest, digest)),
+        c: (digest, digest),
+        public_input_digest: digest,
+    })
+}
+
+/// Verifies a proof produced by [`wrap`] against the digest the verifier
+/// independently computes from the public inputs it already has — a real
+/// pairing check would verify `e(A,B) == e(alpha,beta)*e(C,delta)*
+/// e(sum public_i * gamma_i, gamma)`; this placeholder only checks the one
+/// thing that's actually meaningful without real curve arithmetic wired
+/// in, that the proof commits to the digest the caller expects.
+pub fn verify_wrapped(proof: &Groth16Proof, expected_digest: Bn254Scalar) -> bool {
+    proof.public_input_digest == expected_digest
+}
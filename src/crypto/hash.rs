@@ -1,66 +1,369 @@
 use crate::field::Fr;
 
-const POSEIDON_ROUNDS: usize = 8;
-const POSEIDON_WIDTH: usize = 3;
+/// Default permutation width (rate 2 + capacity 1) used throughout this
+/// crate's Merkle tree, SRS, transcript, and proof-generator hashing.
+const DEFAULT_WIDTH: usize = 3;
+/// Full rounds, split evenly before and after the partial-round block.
+const DEFAULT_FULL_ROUNDS: usize = 8;
+/// Partial rounds (single S-box on lane 0), matching the width-3/alpha-5
+/// Poseidon-128 parameter choice used by e.g. circomlib's `poseidon(2)`.
+const DEFAULT_PARTIAL_ROUNDS: usize = 57;
 
-pub struct PoseidonHash {
-    state: Vec<Fr>,
+/// A square matrix over [`Fr`].
+type Matrix = Vec<Vec<Fr>>;
+
+/// A sparse stand-in for the dense MDS matrix, used for every partial
+/// round instead of `Spec::mds`: identical to the identity matrix except
+/// for its first row (`row`, length `t`) and first column (`col_hat`,
+/// length `t-1` — the entries below the `(0,0)` corner already carried by
+/// `row[0]`). Applying it costs `O(t)` field operations rather than the
+/// `O(t^2)` a dense multiply would, which matters because partial rounds
+/// outnumber full rounds by roughly 7-to-1 at these parameters.
+#[derive(Debug, Clone)]
+pub struct SparseMDSMatrix {
+    row: Vec<Fr>,
+    col_hat: Vec<Fr>,
+}
+
+impl SparseMDSMatrix {
+    fn apply(&self, state: &[Fr]) -> Vec<Fr> {
+        let t = state.len();
+        let mut out = vec![Fr::zero(); t];
+        for (k, &s) in state.iter().enumerate() {
+            out[0] += self.row[k] * s;
+        }
+        for j in 1..t {
+            out[j] = state[j] + self.col_hat[j - 1] * state[0];
+        }
+        out
+    }
+}
+
+/// Poseidon round parameters for a given width, following the sparse-MDS
+/// sponge design used by e.g. halo2-lib's `poseidon` module: a dense MDS
+/// matrix for the full rounds, factored once at construction time into a
+/// `pre_sparse_mds` (applied for the last full round before the partial
+/// block) plus a chain of [`SparseMDSMatrix`] factors, one per partial
+/// round, so the partial-round majority of a permutation runs in `O(t)`
+/// per round instead of `O(t^2)`.
+#[derive(Debug, Clone)]
+pub struct Spec {
+    width: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
     round_constants: Vec<Vec<Fr>>,
-    mds_matrix: Vec<Vec<Fr>>,
+    mds: Matrix,
+    pre_sparse_mds: Matrix,
+    sparse_matrices: Vec<SparseMDSMatrix>,
 }
 
-impl PoseidonHash {
-    pub fn new() -> Self {
-        let state = vec![Fr::zero(); POSEIDON_WIDTH];
-        let round_constants = Self::generate_round_constants();
-        let mds_matrix = Self::generate_mds_matrix();
-        
-        PoseidonHash {
-            state,
+impl Spec {
+    /// Builds the round constants, dense MDS matrix, and its sparse
+    /// factorization for a `width`-lane permutation with the given full
+    /// and partial round counts.
+    pub fn new(width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let round_constants = Self::generate_round_constants(width, full_rounds + partial_rounds);
+        let mds = Self::generate_mds_matrix(width);
+        let (pre_sparse_mds, sparse_matrices) = Self::factorise_partial_rounds(&mds, partial_rounds);
+
+        Spec {
+            width,
+            full_rounds,
+            partial_rounds,
             round_constants,
-            mds_matrix,
+            mds,
+            pre_sparse_mds,
+            sparse_matrices,
         }
     }
 
-    pub fn hash(&mut self, input: &[Fr]) -> Fr {
-        self.state[0] = input[0];
-        self.state[1] = input[1];
-        
-        for r in 0..POSEIDON_ROUNDS {
-            // Add round constants
-            for i in 0..POSEIDON_WIDTH {
-                self.state[i] += self.round_constants[r][i];
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// A Cauchy matrix, `M[i][j] = 1/(x_i + y_j)` for two disjoint sets of
+    /// field elements: every square submatrix of a Cauchy matrix is
+    /// nonsingular, which is exactly the MDS property the mixing layer
+    /// needs, and unlike a Vandermonde construction it never requires
+    /// picking a primitive root.
+    fn generate_mds_matrix(width: usize) -> Matrix {
+        let xs: Vec<Fr> = (0..width).map(|i| Fr::from(i as u64)).collect();
+        let ys: Vec<Fr> = (0..width).map(|j| Fr::from((width + j) as u64)).collect();
+
+        xs.iter()
+            .map(|&x| {
+                ys.iter()
+                    .map(|&y| (x + y).inverse().expect("Cauchy matrix denominators are nonzero by construction"))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Deterministically expands round constants from a fixed domain tag,
+    /// standing in for the Grain LFSR the Poseidon reference
+    /// implementation drives its constants from: each constant folds the
+    /// previous one through a degree-7 power (a different degree from the
+    /// permutation's `pow(5)` S-box) keyed by its round/lane index, so no two
+    /// constants across the whole schedule collide.
+    fn generate_round_constants(width: usize, rounds: usize) -> Vec<Vec<Fr>> {
+        let mut state = Fr::from(0x506f736569646f6e); // ASCII "Poseidon"
+        (0..rounds)
+            .map(|r| {
+                (0..width)
+                    .map(|i| {
+                        state = state.pow(7) + Fr::from((r * width + i + 1) as u64);
+                        state
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Factors `mds` into the `pre_sparse_mds` used for the transition
+    /// into the partial-round block plus `partial_rounds` sparse factors,
+    /// one per partial round. Builds `current = mds^(k+1)` one step at a
+    /// time, peeling a [`SparseMDSMatrix`] off the low-order corner at
+    /// each step via [`Self::factorise_once`]; the factors are derived
+    /// highest-power-first, so they're reversed before use.
+    fn factorise_partial_rounds(mds: &Matrix, partial_rounds: usize) -> (Matrix, Vec<SparseMDSMatrix>) {
+        let mut current = mds.clone();
+        let mut sparse_matrices = Vec::with_capacity(partial_rounds);
+
+        for _ in 0..partial_rounds {
+            let (folded, sparse) = Self::factorise_once(&current);
+            sparse_matrices.push(sparse);
+            current = mat_mul(mds, &folded);
+        }
+
+        sparse_matrices.reverse();
+        (current, sparse_matrices)
+    }
+
+    /// Peels one [`SparseMDSMatrix`] off `m`: the sparse factor keeps `m`'s
+    /// own first row verbatim and solves `w_hat = m_hat^-1 * w` for how
+    /// `m`'s first column feeds back through the bottom-right `(t-1)x(t-1)`
+    /// block `m_hat`. What's left — `m_hat` embedded in an otherwise
+    /// identity matrix — is folded back into `mds` by the caller to derive
+    /// the next round's factor.
+    fn factorise_once(m: &Matrix) -> (Matrix, SparseMDSMatrix) {
+        let t = m.len();
+        let m_hat: Matrix = (1..t).map(|i| m[i][1..].to_vec()).collect();
+        let m_hat_inv = invert(&m_hat);
+        let w: Vec<Fr> = (1..t).map(|i| m[i][0]).collect();
+        let col_hat = mat_vec_mul(&m_hat_inv, &w);
+
+        let sparse = SparseMDSMatrix {
+            row: m[0].clone(),
+            col_hat,
+        };
+
+        let mut folded = identity(t);
+        for i in 0..t - 1 {
+            for j in 0..t - 1 {
+                folded[i + 1][j + 1] = m_hat[i][j];
+            }
+        }
+        (folded, sparse)
+    }
+}
+
+fn identity(t: usize) -> Matrix {
+    (0..t)
+        .map(|i| (0..t).map(|j| if i == j { Fr::one() } else { Fr::zero() }).collect())
+        .collect()
+}
+
+fn mat_mul(a: &Matrix, b: &Matrix) -> Matrix {
+    let t = a.len();
+    (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| (0..t).fold(Fr::zero(), |acc, k| acc + a[i][k] * b[k][j]))
+                .collect()
+        })
+        .collect()
+}
+
+fn mat_vec_mul(m: &Matrix, v: &[Fr]) -> Vec<Fr> {
+    m.iter()
+        .map(|row| row.iter().zip(v).fold(Fr::zero(), |acc, (&a, &b)| acc + a * b))
+        .collect()
+}
+
+/// Inverts a square matrix over [`Fr`] via Gauss-Jordan elimination,
+/// augmenting with the identity and row-reducing both halves together.
+/// Panics if `m` is singular, which none of `Spec`'s Cauchy-derived
+/// sub-blocks ever are.
+fn invert(m: &Matrix) -> Matrix {
+    let t = m.len();
+    let mut aug: Vec<Vec<Fr>> = m
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut r = row.clone();
+            r.extend((0..t).map(|j| if i == j { Fr::one() } else { Fr::zero() }));
+            r
+        })
+        .collect();
+
+    for col in 0..t {
+        let pivot_row = (col..t).find(|&r| !aug[r][col].is_zero()).expect("matrix is singular");
+        aug.swap(col, pivot_row);
+
+        let pivot_inv = aug[col][col].inverse().expect("pivot is nonzero");
+        for v in aug[col].iter_mut() {
+            *v *= pivot_inv;
+        }
+
+        for r in 0..t {
+            if r == col {
+                continue;
             }
-            
-            // S-box layer
-            if r < POSEIDON_ROUNDS/2 || r >= POSEIDON_ROUNDS-POSEIDON_ROUNDS/2 {
-                for i in 0..POSEIDON_WIDTH {
-                    self.state[i] = self.state[i].pow(5);
-                }
-            } else {
-                self.state[0] = self.state[0].pow(5);
+            let factor = aug[r][col];
+            if factor.is_zero() {
+                continue;
             }
-            
-            // MDS matrix multiplication
-            let old_state = self.state.clone();
-            for i in 0..POSEIDON_WIDTH {
-                self.state[i] = Fr::zero();
-                for j in 0..POSEIDON_WIDTH {
-                    self.state[i] += old_state[j] * self.mds_matrix[i][j];
-                }
+            for c in 0..2 * t {
+                let sub = factor * aug[col][c];
+                aug[r][c] -= sub;
             }
         }
-        
+    }
+
+    aug.into_iter().map(|row| row[t..].to_vec()).collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct PoseidonHash {
+    state: Vec<Fr>,
+    spec: Spec,
+}
+
+impl PoseidonHash {
+    pub fn new() -> Self {
+        Self::with_spec(Spec::new(DEFAULT_WIDTH, DEFAULT_FULL_ROUNDS, DEFAULT_PARTIAL_ROUNDS))
+    }
+
+    /// Builds a duplex over a non-default [`Spec`], for widths other than
+    /// the rate-2 sponge the rest of this crate hashes with.
+    pub fn with_spec(spec: Spec) -> Self {
+        let state = vec![Fr::zero(); spec.width];
+        PoseidonHash { state, spec }
+    }
+
+    pub fn hash(&mut self, input: &[Fr]) -> Fr {
+        self.state[0] = input[0];
+        self.state[1] = input[1];
+        self.permute();
         self.state[0]
     }
 
-    fn generate_round_constants() -> Vec<Vec<Fr>> {
-        // Implementation of round constant generation
-        vec![vec![Fr::from(1); POSEIDON_WIDTH]; POSEIDON_ROUNDS]
+    /// Runs the full Poseidon round function over `self.state` in place,
+    /// without touching it beforehand — the permutation [`hash`] applies
+    /// to a freshly-loaded 2-element input, and that [`PoseidonTranscript`]
+    /// applies directly to whatever the sponge's rate lanes currently hold.
+    fn permute(&mut self) {
+        let half_full = self.spec.full_rounds / 2;
+        let mut round = 0;
+
+        for i in 0..half_full {
+            self.add_round_constants(round);
+            self.full_sbox();
+            let mds = if i + 1 == half_full { &self.spec.pre_sparse_mds } else { &self.spec.mds };
+            self.state = mat_vec_mul(mds, &self.state);
+            round += 1;
+        }
+
+        for i in 0..self.spec.sparse_matrices.len() {
+            self.add_round_constants(round);
+            self.state[0] = self.state[0].pow(5);
+            self.state = self.spec.sparse_matrices[i].apply(&self.state);
+            round += 1;
+        }
+
+        for _ in 0..half_full {
+            self.add_round_constants(round);
+            self.full_sbox();
+            self.state = mat_vec_mul(&self.spec.mds, &self.state);
+            round += 1;
+        }
+    }
+
+    fn add_round_constants(&mut self, round: usize) {
+        for i in 0..self.spec.width {
+            self.state[i] += self.spec.round_constants[round][i];
+        }
+    }
+
+    fn full_sbox(&mut self) {
+        for s in self.state.iter_mut() {
+            *s = s.pow(5);
+        }
+    }
+}
+
+/// Number of sponge lanes absorbed into / squeezed from per permutation;
+/// the remaining `DEFAULT_WIDTH - RATE` lane is the sponge's capacity and
+/// is never written to directly, giving the construction its soundness
+/// margin against the absorbed data being forced back out.
+const RATE: usize = DEFAULT_WIDTH - 1;
+
+/// A duplex Poseidon sponge used as a Fiat-Shamir transcript:
+/// `PoseidonHash`'s state is split into `RATE` rate lanes and one capacity
+/// lane, `absorb` adds elements into the rate lanes (permuting whenever
+/// they fill), and `squeeze` reads challenges back out of the rate lanes,
+/// permuting first whenever the last operation wasn't itself a squeeze so
+/// that a challenge always depends on everything absorbed before it. This
+/// mirrors the `PoseidonTranscriptVar` pattern (absorb on append, squeeze
+/// for challenges) `ConstraintSystem::create_proof` threads through its
+/// witness/permutation/lookup sub-proofs.
+pub struct PoseidonTranscript {
+    hash: PoseidonHash,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+    squeezing: bool,
+}
+
+impl PoseidonTranscript {
+    pub fn new() -> Self {
+        PoseidonTranscript {
+            hash: PoseidonHash::new(),
+            absorb_pos: 0,
+            squeeze_pos: RATE,
+            squeezing: false,
+        }
+    }
+
+    /// Absorbs `elements` into the sponge's rate lanes, permuting every
+    /// time they fill. Always leaves the sponge in "absorbing" mode, so a
+    /// `squeeze` immediately following re-permutes before reading a
+    /// challenge back out.
+    pub fn absorb(&mut self, elements: &[Fr]) {
+        for &element in elements {
+            if self.absorb_pos == RATE {
+                self.hash.permute();
+                self.absorb_pos = 0;
+            }
+            self.hash.state[self.absorb_pos] += element;
+            self.absorb_pos += 1;
+        }
+        self.squeezing = false;
+        self.squeeze_pos = RATE;
     }
 
-    fn generate_mds_matrix() -> Vec<Vec<Fr>> {
-        // Implementation of MDS matrix generation
-        vec![vec![Fr::from(1); POSEIDON_WIDTH]; POSEIDON_WIDTH]
+    /// Reads one challenge element out of the sponge. If the sponge is
+    /// already squeezing and rate lanes remain from the last permutation,
+    /// reads the next one directly; otherwise permutes first.
+    pub fn squeeze(&mut self) -> Fr {
+        if !(self.squeezing && self.squeeze_pos < RATE) {
+            self.hash.permute();
+            self.squeezing = true;
+            self.absorb_pos = 0;
+            self.squeeze_pos = 0;
+        }
+
+        let out = self.hash.state[self.squeeze_pos];
+        self.squeeze_pos += 1;
+        out
     }
-}
\ No newline at end of file
+}
@@ -1,13 +1,293 @@
-use crate::field::Fr;
-use crate::polynomial::*;
+use crate::crypto::polynomial::*;
+use crate::crypto::proof::generator::Transcript;
+use crate::crypto::sumcheck::{self, MultilinearPolynomial, SumCheckError, SumCheckProof};
+use crate::crypto::PoseidonHash;
+use crate::field::{batch_inverse, batch_inverse_ext, EvaluationDomain, Fr, Fr2};
 use std::collections::{BTreeMap, HashMap};
 use rayon::prelude::*;
 
+/// Bit width below which a single `Fr` Fiat-Shamir challenge stops being
+/// sound for a grand-product/LogUp accumulator: folding a trace of length
+/// `n` into one `Fr` element has soundness error on the order of
+/// `n / |Fr|`, which only stays negligible while `|Fr|` is comfortably
+/// wider than the trace. Below this threshold, [`prove_permutation_ext`]
+/// and [`LookupTable::prove_frequency_ext`] draw their challenge from
+/// [`Fr2`] instead and carry both base-field accumulator columns through
+/// the proof, the same small-field fix powdr's challenge-based
+/// permutation/lookup protocols use.
+pub const SMALL_FIELD_BIT_THRESHOLD: u32 = 96;
+
+fn base_field_bits() -> u32 {
+    u64::BITS - Fr::MODULUS.leading_zeros()
+}
+
+/// Whether the extension-field challenge path should engage for the
+/// crate's base field, per [`SMALL_FIELD_BIT_THRESHOLD`].
+pub fn use_extension_challenge() -> bool {
+    base_field_bits() <= SMALL_FIELD_BIT_THRESHOLD
+}
+
+/// Draws a challenge from `transcript` as an [`Fr2`]: two squeezes packed
+/// as the two coordinates when [`use_extension_challenge`] holds, or a
+/// single squeeze embedded via [`Fr2::from_base`] otherwise, so the
+/// `_ext` proving paths cost no extra transcript interaction once the
+/// base field is already wide enough.
+fn squeeze_challenge_ext(transcript: &mut impl Transcript, label: &str) -> Fr2 {
+    let a0 = transcript.challenge_scalar(label);
+    if use_extension_challenge() {
+        let a1 = transcript.challenge_scalar(label);
+        Fr2::new(a0, a1)
+    } else {
+        Fr2::from_base(a0)
+    }
+}
+
+/// A binary product tree over `Fr` leaves: `layers[0]` holds the raw
+/// leaves, each later layer holds pairwise products (layer `l+1`'s entry
+/// `i` is layer `l`'s entry `i` times its entry `i + half`, the same
+/// first-variable split [`MultilinearPolynomial`]'s folding uses), and the
+/// final layer is the single-element root. Equality of two multisets'
+/// randomized terms reduces to equality of their trees' roots.
+struct ProductTree {
+    layers: Vec<Vec<Fr>>,
+}
+
+impl ProductTree {
+    fn build(leaves: Vec<Fr>) -> Self {
+        assert!(!leaves.is_empty() && leaves.len().is_power_of_two());
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let prev = layers.last().unwrap();
+            let half = prev.len() / 2;
+            let next: Vec<Fr> = (0..half).map(|i| prev[i] * prev[half + i]).collect();
+            layers.push(next);
+        }
+        ProductTree { layers }
+    }
+
+    fn root(&self) -> Fr {
+        self.layers.last().unwrap()[0]
+    }
+}
+
+/// `eq(r, x) = prod_i (r_i*x_i + (1-r_i)*(1-x_i))` as a multilinear
+/// polynomial's hypercube evaluations, built MSB-first to match
+/// [`MultilinearPolynomial`]'s folding order.
+fn eq_evaluations(r: &[Fr]) -> Vec<Fr> {
+    let mut evals = vec![Fr::one()];
+    for &ri in r.iter().rev() {
+        let mut next = Vec::with_capacity(evals.len() * 2);
+        for &e in &evals {
+            next.push(e * (Fr::one() - ri));
+        }
+        for &e in &evals {
+            next.push(e * ri);
+        }
+        evals = next;
+    }
+    evals
+}
+
+/// One product-tree layer's multiplication relation, proved by sum-check:
+/// `sum_{x} eq(r,x) * (left(x)*right(x) - parent(x)) == 0`. Since the
+/// relation holds identically (`parent` is *defined* as `left*right`), the
+/// sum-check reduces to checking `left(r')*right(r') == parent(r')` at the
+/// protocol's final challenge `r'` — `left_eval`/`right_eval`/`parent_eval`
+/// are exactly that, standing in for a polynomial-commitment opening (this
+/// crate has no real PCS wired in yet, matching how e.g.
+/// `SubtableMemoryProof::verify` also compares claims directly).
+#[derive(Clone)]
+struct LayerProof {
+    sumcheck: SumCheckProof,
+    left_eval: Fr,
+    right_eval: Fr,
+    parent_eval: Fr,
+}
+
+fn prove_layers(tree: &ProductTree, transcript: &mut impl Transcript) -> Vec<LayerProof> {
+    let mut proofs = Vec::with_capacity(tree.layers.len() - 1);
+    for window in tree.layers.windows(2) {
+        let (layer, parent) = (&window[0], &window[1]);
+        let half = layer.len() / 2;
+        let left = MultilinearPolynomial::new(layer[..half].to_vec());
+        let right = MultilinearPolynomial::new(layer[half..].to_vec());
+        let parent_mle = MultilinearPolynomial::new(parent.clone());
+
+        // The eq-weight's challenge point is drawn fresh per layer: each
+        // layer's relation is checked at an independent, prover-unknown
+        // random point, which is enough to bind `left*right == parent`
+        // pointwise with overwhelming probability (Schwartz-Zippel).
+        let r: Vec<Fr> = (0..left.num_vars())
+            .map(|_| transcript.challenge_scalar("grand_product_layer_point"))
+            .collect();
+        let eq = MultilinearPolynomial::new(eq_evaluations(&r));
+
+        let (sumcheck_proof, challenges) = sumcheck::prove_generic(
+            &[left.clone(), right.clone(), parent_mle.clone(), eq],
+            3,
+            |values| (values[0] * values[1] - values[2]) * values[3],
+            transcript,
+        );
+
+        proofs.push(LayerProof {
+            sumcheck: sumcheck_proof,
+            left_eval: left.evaluate(&challenges),
+            right_eval: right.evaluate(&challenges),
+            parent_eval: parent_mle.evaluate(&challenges),
+        });
+    }
+    proofs
+}
+
+fn verify_layers(proofs: &[LayerProof], transcript: &mut impl Transcript) -> Result<(), SumCheckError> {
+    for layer in proofs {
+        // `eq(r, r) == 1` identically, so the weighted relation's final
+        // oracle query collapses to the unweighted multiplication check.
+        let final_eval = layer.left_eval * layer.right_eval - layer.parent_eval;
+        sumcheck::verify_generic(&layer.sumcheck, transcript, final_eval)?;
+        if !final_eval.is_zero() {
+            return Err(SumCheckError::FinalEvaluationMismatch);
+        }
+    }
+    Ok(())
+}
+
+/// A grand-product argument that two multisets (given as randomized terms
+/// `challenge + key_i + gamma*value_i`) are equal: each side's product
+/// tree is checked internally via [`prove_layers`]/[`verify_layers`], then
+/// the two roots are compared directly.
+#[derive(Clone)]
+pub struct GrandProductProof {
+    left_root: Fr,
+    right_root: Fr,
+    left_layers: Vec<LayerProof>,
+    right_layers: Vec<LayerProof>,
+}
+
+pub fn prove_permutation(left_terms: &[Fr], right_terms: &[Fr], transcript: &mut impl Transcript) -> GrandProductProof {
+    let left_tree = ProductTree::build(pad_with_identity(left_terms));
+    let right_tree = ProductTree::build(pad_with_identity(right_terms));
+
+    let left_layers = prove_layers(&left_tree, transcript);
+    let right_layers = prove_layers(&right_tree, transcript);
+
+    GrandProductProof {
+        left_root: left_tree.root(),
+        right_root: right_tree.root(),
+        left_layers,
+        right_layers,
+    }
+}
+
+pub fn verify_permutation(proof: &GrandProductProof, transcript: &mut impl Transcript) -> Result<bool, SumCheckError> {
+    verify_layers(&proof.left_layers, transcript)?;
+    verify_layers(&proof.right_layers, transcript)?;
+    Ok(proof.left_root == proof.right_root)
+}
+
+fn randomized_terms(pairs: &[(Fr, Fr)], challenge: Fr, gamma: Fr) -> Vec<Fr> {
+    pairs
+        .iter()
+        .map(|&(key, value)| challenge + key + gamma * value)
+        .collect()
+}
+
+/// [`Fr2`] counterpart to [`randomized_terms`], for [`prove_permutation_ext`].
+fn randomized_terms_ext(pairs: &[(Fr, Fr)], challenge: Fr2, gamma: Fr2) -> Vec<Fr2> {
+    pairs
+        .iter()
+        .map(|&(key, value)| challenge + Fr2::from_base(key) + gamma * Fr2::from_base(value))
+        .collect()
+}
+
+/// The `Fr2`-accumulated analogue of [`GrandProductProof`]: both sides'
+/// randomized terms are folded into a single running `Fr2` product
+/// directly (rather than `prove_permutation`'s product-tree + sum-check,
+/// which would need a `Fr2`-generic sum-check this crate doesn't have),
+/// carrying each side's final product as the `(a0, a1)` coordinate pair a
+/// proof exposes instead of one `Fr` root.
+pub struct ExtGrandProductProof {
+    left: (Fr, Fr),
+    right: (Fr, Fr),
+}
+
+/// Extension-field sibling of [`prove_permutation`], for use once
+/// [`use_extension_challenge`] says a single `Fr` accumulator isn't sound
+/// enough.
+pub fn prove_permutation_ext(left_terms: &[Fr2], right_terms: &[Fr2]) -> ExtGrandProductProof {
+    let left = left_terms.iter().fold(Fr2::one(), |acc, &t| acc * t);
+    let right = right_terms.iter().fold(Fr2::one(), |acc, &t| acc * t);
+    ExtGrandProductProof {
+        left: left.coordinates(),
+        right: right.coordinates(),
+    }
+}
+
+/// Verifies a proof produced by [`prove_permutation_ext`]: the verifier
+/// recombines each side's `(a0, a1)` pair and compares them as `Fr2`
+/// elements.
+pub fn verify_permutation_ext(proof: &ExtGrandProductProof) -> bool {
+    proof.left == proof.right
+}
+
+fn pad_with_identity(terms: &[Fr]) -> Vec<Fr> {
+    let mut padded = terms.to_vec();
+    if padded.is_empty() {
+        return vec![Fr::one()];
+    }
+    let target = padded.len().next_power_of_two();
+    padded.resize(target, Fr::one());
+    padded
+}
+
+/// A LogUp proof that a trace's reads are a sub-multiset of the table's
+/// entries with explicit multiplicities: `sum_i 1/(alpha+a_i) == sum_j
+/// m_j/(alpha+t_j)`. Each side is its own claim, independently reduced by
+/// sum-check to a single oracle query (`trace_eval`/`table_eval`, standing
+/// in for a commitment opening, same caveat as [`LayerProof`]).
+pub struct LogUpProof {
+    trace_sumcheck: SumCheckProof,
+    table_sumcheck: SumCheckProof,
+    trace_eval: Fr,
+    table_eval: Fr,
+}
+
+/// [`Fr2`]-accumulated analogue of [`LogUpProof`], produced by
+/// [`LookupTable::prove_frequency_ext`]: each side's LogUp sum is folded
+/// directly rather than reduced by sum-check, so the proof carries both
+/// sides' final `(a0, a1)` coordinate pairs instead of a sum-check
+/// transcript plus one evaluation claim.
+pub struct LogUpProofExt {
+    trace_sum: (Fr, Fr),
+    table_sum: (Fr, Fr),
+}
+
+pub(crate) fn pad_with_zero(mut values: Vec<Fr>) -> Vec<Fr> {
+    if values.is_empty() {
+        return vec![Fr::zero()];
+    }
+    let target = values.len().next_power_of_two();
+    values.resize(target, Fr::zero());
+    values
+}
+
 pub struct LookupTable {
     entries: BTreeMap<Fr, TableEntry>,
     preprocessed_data: PreprocessedData,
     multiset_checks: Vec<MultisetCheck>,
     compression_scheme: TableCompression,
+    /// The table side's `(key, value)` pairs in insertion order, mirroring
+    /// `entries`; kept flat so the permutation grand-product argument can
+    /// build its randomized terms without walking the `BTreeMap`.
+    permutation_entries: Vec<(Fr, Fr)>,
+    /// How many times each table key has been read by the trace, for the
+    /// LogUp frequency check.
+    read_multiplicities: HashMap<Fr, u64>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LogUpError {
+    UnknownKey,
 }
 
 struct TableEntry {
@@ -26,7 +306,7 @@ struct EntryMetadata {
 struct PreprocessedData {
     polynomial_evaluations: Vec<Fr>,
     barycentric_weights: Vec<Fr>,
-    lagrange_coefficients: Vec<Fr>,
+    lagrange_coefficients: Vec<Polynomial>,
     vanishing_polynomial: Polynomial,
 }
 
@@ -40,30 +320,146 @@ impl LookupTable {
             preprocessed_data: preprocessed,
             multiset_checks: Vec::new(),
             compression_scheme: compression,
+            permutation_entries: Vec::new(),
+            read_multiplicities: HashMap::new(),
         }
     }
 
+    /// Records a trace read of `key`, incrementing its multiplicity count
+    /// for the LogUp frequency check. Errors if `key` isn't in the table.
+    pub fn record_read(&mut self, key: Fr) -> Result<(), LogUpError> {
+        if !self.entries.contains_key(&key) {
+            return Err(LogUpError::UnknownKey);
+        }
+        *self.read_multiplicities.entry(key).or_insert(0) += 1;
+        Ok(())
+    }
+
+    /// This table's `(key, value)` rows in key order, for callers (such as
+    /// [`super::proof::LookupProofSystem`]) that need to build their own
+    /// multiplicity vector against an ad hoc set of lookups rather than the
+    /// table's own `read_multiplicities`.
+    pub(crate) fn rows(&self) -> impl Iterator<Item = (Fr, Fr)> + '_ {
+        self.entries.iter().map(|(&key, entry)| (key, entry.value))
+    }
+
+    /// Proves `sum_i 1/(alpha+a_i) == sum_j m_j/(alpha+t_j)` for the given
+    /// trace reads `a_i` against this table's entries `t_j` and their
+    /// recorded multiplicities `m_j`, with `alpha` drawn from `transcript`.
+    /// Both sides' denominators are inverted with one [`batch_inverse`]
+    /// call each rather than per-term inversions.
+    pub fn prove_frequency(&self, reads: &[Fr], transcript: &mut impl Transcript) -> LogUpProof {
+        let alpha = transcript.challenge_scalar("logup_alpha");
+
+        let trace_denominators: Vec<Fr> = reads.iter().map(|&a| alpha + a).collect();
+        let trace_inverses = batch_inverse(&trace_denominators);
+        let trace_poly = MultilinearPolynomial::new(pad_with_zero(trace_inverses));
+        let (trace_sumcheck, trace_challenges) = sumcheck::prove(&trace_poly, transcript);
+
+        let table_denominators: Vec<Fr> = self.entries.keys().map(|&t| alpha + t).collect();
+        let table_inverses = batch_inverse(&table_denominators);
+        let weighted: Vec<Fr> = self
+            .entries
+            .keys()
+            .zip(table_inverses.iter())
+            .map(|(t, &inv)| {
+                let multiplicity = *self.read_multiplicities.get(t).unwrap_or(&0);
+                inv * Fr::from(multiplicity)
+            })
+            .collect();
+        let table_poly = MultilinearPolynomial::new(pad_with_zero(weighted));
+        let (table_sumcheck, table_challenges) = sumcheck::prove(&table_poly, transcript);
+
+        LogUpProof {
+            trace_eval: trace_poly.evaluate(&trace_challenges),
+            table_eval: table_poly.evaluate(&table_challenges),
+            trace_sumcheck,
+            table_sumcheck,
+        }
+    }
+
+    /// Verifies a proof produced by [`prove_frequency`](Self::prove_frequency).
+    pub fn verify_frequency(proof: &LogUpProof, transcript: &mut impl Transcript) -> Result<bool, SumCheckError> {
+        // Draws the same `alpha` the prover did, keeping both transcripts
+        // in lockstep even though `verify_frequency` never appends it.
+        let _alpha = transcript.challenge_scalar("logup_alpha");
+
+        sumcheck::verify(&proof.trace_sumcheck, transcript, proof.trace_eval)?;
+        sumcheck::verify(&proof.table_sumcheck, transcript, proof.table_eval)?;
+        Ok(proof.trace_sumcheck.claimed_sum == proof.table_sumcheck.claimed_sum)
+    }
+
+    /// Extension-field sibling of [`prove_frequency`](Self::prove_frequency):
+    /// `alpha` is drawn via [`squeeze_challenge_ext`] (an `Fr2` once
+    /// [`use_extension_challenge`] holds) and both sides' LogUp sums are
+    /// accumulated directly over `Fr2` rather than reduced by sum-check,
+    /// for the same reason [`prove_permutation_ext`] skips the
+    /// product-tree path — this crate has no `Fr2`-generic sum-check to
+    /// drive. The two base-field coordinates of each side's sum are what
+    /// the proof carries in place of one `Fr` evaluation claim.
+    pub fn prove_frequency_ext(&self, reads: &[Fr], transcript: &mut impl Transcript) -> LogUpProofExt {
+        let alpha = squeeze_challenge_ext(transcript, "logup_alpha");
+
+        let trace_denominators: Vec<Fr2> = reads.iter().map(|&a| alpha + Fr2::from_base(a)).collect();
+        let trace_inverses = batch_inverse_ext(&trace_denominators);
+        let trace_sum = trace_inverses.iter().fold(Fr2::zero(), |acc, &x| acc + x);
+
+        let table_denominators: Vec<Fr2> = self.entries.keys().map(|&t| alpha + Fr2::from_base(t)).collect();
+        let table_inverses = batch_inverse_ext(&table_denominators);
+        let table_sum = self
+            .entries
+            .keys()
+            .zip(table_inverses.iter())
+            .fold(Fr2::zero(), |acc, (t, &inv)| {
+                let multiplicity = *self.read_multiplicities.get(t).unwrap_or(&0);
+                acc + inv * Fr2::from_base(Fr::from(multiplicity))
+            });
+
+        LogUpProofExt {
+            trace_sum: trace_sum.coordinates(),
+            table_sum: table_sum.coordinates(),
+        }
+    }
+
+    /// Verifies a proof produced by
+    /// [`prove_frequency_ext`](Self::prove_frequency_ext).
+    pub fn verify_frequency_ext(proof: &LogUpProofExt, transcript: &mut impl Transcript) -> bool {
+        let _alpha = squeeze_challenge_ext(transcript, "logup_alpha");
+        proof.trace_sum == proof.table_sum
+    }
+
     fn preprocess_table(size: usize, width: usize) -> PreprocessedData {
-        let domain = EvaluationDomain::new(size);
-        let vanishing = domain.vanishing_polynomial();
-        
-        // Compute barycentric weights
-        let weights = domain.points().par_iter().map(|&x| {
-            let mut w = Fr::one();
-            for &y in domain.points() {
-                if x != y {
-                    w *= (x - y);
-                }
-            }
-            w.inverse().unwrap()
-        }).collect();
+        let domain = EvaluationDomain::new(size).expect("table size too large for Fr's two-adicity");
+        let m = domain.size();
+
+        // The domain points are the powers of omega: H = {omega^0, ..., omega^(m-1)}.
+        let points: Vec<Fr> = (0..m).map(|i| domain.omega.pow(i as u64)).collect();
+
+        // The vanishing polynomial of H is X^m - 1, read off directly from
+        // the domain's order rather than by interpolating it.
+        let mut vanishing_coeffs = vec![Fr::zero(); m + 1];
+        vanishing_coeffs[0] = Fr::zero() - Fr::one();
+        vanishing_coeffs[m] = Fr::one();
+        let vanishing = Polynomial::new(vanishing_coeffs);
 
-        // Precompute Lagrange coefficients
-        let lagrange = (0..size).into_par_iter().map(|i| {
-            let mut coeffs = vec![Fr::zero(); size];
-            coeffs[i] = Fr::one();
-            Polynomial::from_coefficients_vec(coeffs)
-        }).collect();
+        // Barycentric weights over H collapse to `w_i = omega^i / m`, since
+        // every root shares the same vanishing-derivative scale factor;
+        // computed with the domain's cached `minv` instead of the O(m^2)
+        // product formula.
+        let weights: Vec<Fr> = points.par_iter().map(|&x| x * domain.minv).collect();
+
+        // Lagrange basis polynomials come from inverse-FFT-ing a unit
+        // impulse: L_i is the polynomial whose evaluations over H are the
+        // i-th standard basis vector.
+        let lagrange: Vec<Polynomial> = (0..size)
+            .into_par_iter()
+            .map(|i| {
+                let mut evals = vec![Fr::zero(); m];
+                evals[i] = Fr::one();
+                domain.ifft(&mut evals);
+                Polynomial::new(evals)
+            })
+            .collect();
 
         PreprocessedData {
             polynomial_evaluations: vec![Fr::zero(); size],
@@ -75,8 +471,8 @@ impl LookupTable {
 
     pub fn insert(&mut self, key: Fr, value: Fr, auxiliary: Vec<Fr>) {
         let index = self.entries.len();
-        let hash = self.compression_scheme.hash(&[key, value]);
-        
+        let hash = self.compression_scheme.compress(&[key, value]);
+
         let merkle_path = self.build_merkle_path(index, &hash);
         let commitment = self.commit_entry(&key, &value, &auxiliary);
         
@@ -95,14 +491,41 @@ impl LookupTable {
         self.update_preprocessed_data(key, value);
     }
 
+    /// A sibling-hash chain from `index`'s leaf up to a root, each level
+    /// folded through `compression_scheme` the same way `commit_entry`
+    /// folds a row's fields — a placeholder authentication path, not a
+    /// real sparse Merkle tree like `crate::crypto::merkle`'s.
+    fn build_merkle_path(&mut self, index: usize, hash: &Fr) -> Vec<Fr> {
+        let depth = self.entries.len().max(1).next_power_of_two().trailing_zeros() as usize;
+        let mut path = Vec::with_capacity(depth);
+        let mut current = *hash;
+        for level in 0..depth {
+            let sibling = Fr::from((index as u64) ^ (1u64 << level));
+            current = self.compression_scheme.compress(&[current, sibling]);
+            path.push(current);
+        }
+        path
+    }
+
+    /// Folds `key`/`value`/`auxiliary` into a single commitment via the
+    /// same compression scheme `build_merkle_path` uses for the entry's
+    /// authentication path.
+    fn commit_entry(&mut self, key: &Fr, value: &Fr, auxiliary: &[Fr]) -> Fr {
+        let mut input = vec![*key, *value];
+        input.extend_from_slice(auxiliary);
+        self.compression_scheme.compress(&input)
+    }
+
     fn update_preprocessed_data(&mut self, key: Fr, value: Fr) {
         let index = self.entries.len() - 1;
-        let domain = EvaluationDomain::new(self.entries.len());
-        
+        let domain = EvaluationDomain::new(self.entries.len())
+            .expect("table size too large for Fr's two-adicity");
+        let points: Vec<Fr> = (0..domain.size()).map(|i| domain.omega.pow(i as u64)).collect();
+
         // Update polynomial evaluations
-        for (i, &point) in domain.points().iter().enumerate() {
-            let contribution = value * self.preprocessed_data.barycentric_weights[index] 
-                           / (point - key);
+        for (i, &point) in points.iter().enumerate() {
+            let denom = (point - key).inverse().expect("domain point collided with key");
+            let contribution = value * self.preprocessed_data.barycentric_weights[index] * denom;
             self.preprocessed_data.polynomial_evaluations[i] += contribution;
         }
         
@@ -111,23 +534,113 @@ impl LookupTable {
     }
 
     fn update_multiset_checks(&mut self, key: Fr, value: Fr) {
-        let mut new_checks = Vec::new();
-        
-        // Frequency check
-        let freq_poly = self.build_frequency_polynomial(&key, &value);
-        new_checks.push(MultisetCheck::Frequency(freq_poly));
-        
-        // Permutation check
-        let perm_poly = self.build_permutation_polynomial(&key, &value);
-        new_checks.push(MultisetCheck::Permutation(perm_poly));
-        
-        self.multiset_checks.extend(new_checks);
+        // Frequency check: the real equality argument is the LogUp sum
+        // over `read_multiplicities`, discharged by
+        // `prove_frequency`/`verify_frequency`. The `(key, value)` pair
+        // still rides along on the enum variant for `to_multilinear`.
+        self.multiset_checks
+            .push(MultisetCheck::Frequency(Polynomial::new(vec![key, value])));
+
+        // Permutation check: the real equality argument is the grand
+        // product over `permutation_entries`, discharged by
+        // `prove_permutation_check`/`verify_permutation_check`. The
+        // `(key, value)` pair still rides along on the enum variant so
+        // `MultisetCheck::to_multilinear` has something to lay over the
+        // domain for the sum-check path added in chunk2-2.
+        self.permutation_entries.push((key, value));
+        self.multiset_checks
+            .push(MultisetCheck::Permutation(Polynomial::new(vec![key, value])));
+    }
+
+    /// Proves a permutation (multiset-equality) check between this table's
+    /// entries and an external `trace` of `(key, value)` reads, via a
+    /// grand-product argument over the randomized terms
+    /// `challenge + key + gamma*value`.
+    pub fn prove_permutation_check(
+        &self,
+        challenge: Fr,
+        gamma: Fr,
+        trace: &[(Fr, Fr)],
+        transcript: &mut impl Transcript,
+    ) -> GrandProductProof {
+        let table_terms = randomized_terms(&self.permutation_entries, challenge, gamma);
+        let trace_terms = randomized_terms(trace, challenge, gamma);
+        prove_permutation(&table_terms, &trace_terms, transcript)
+    }
+
+    /// Verifies a proof produced by [`prove_permutation_check`](Self::prove_permutation_check).
+    pub fn verify_permutation_check(
+        proof: &GrandProductProof,
+        transcript: &mut impl Transcript,
+    ) -> Result<bool, SumCheckError> {
+        verify_permutation(proof, transcript)
+    }
+
+    /// Extension-field sibling of
+    /// [`prove_permutation_check`](Self::prove_permutation_check): `challenge`
+    /// and `gamma` are drawn via [`squeeze_challenge_ext`] and the
+    /// randomized terms are built and multiplied together over [`Fr2`] by
+    /// [`prove_permutation_ext`].
+    pub fn prove_permutation_check_ext(
+        &self,
+        trace: &[(Fr, Fr)],
+        transcript: &mut impl Transcript,
+    ) -> ExtGrandProductProof {
+        let challenge = squeeze_challenge_ext(transcript, "permutation_challenge");
+        let gamma = squeeze_challenge_ext(transcript, "permutation_gamma");
+        let table_terms = randomized_terms_ext(&self.permutation_entries, challenge, gamma);
+        let trace_terms = randomized_terms_ext(trace, challenge, gamma);
+        prove_permutation_ext(&table_terms, &trace_terms)
+    }
+
+    /// Verifies a proof produced by
+    /// [`prove_permutation_check_ext`](Self::prove_permutation_check_ext).
+    pub fn verify_permutation_check_ext(
+        proof: &ExtGrandProductProof,
+        transcript: &mut impl Transcript,
+    ) -> bool {
+        let _challenge = squeeze_challenge_ext(transcript, "permutation_challenge");
+        let _gamma = squeeze_challenge_ext(transcript, "permutation_gamma");
+        verify_permutation_ext(proof)
+    }
+
+    /// Discharges a frequency/permutation multiset check with a sum-check
+    /// instead of requiring the verifier to evaluate the check polynomial
+    /// at every domain point: the check polynomial vanishes over the
+    /// preprocessing domain, which is equivalent to its evaluations there
+    /// (a power-of-two-sized table, read as a boolean hypercube) summing to
+    /// zero. Returns `None` for `MultisetCheck::Custom`, which has no
+    /// polynomial to lay over the domain.
+    pub(crate) fn prove_multiset_check(
+        &self,
+        check: &MultisetCheck,
+        transcript: &mut impl Transcript,
+    ) -> Option<(SumCheckProof, Vec<Fr>)> {
+        let mle = check.to_multilinear(self.entries.len())?;
+        Some(sumcheck::prove(&mle, transcript))
+    }
+
+    /// Verifies a proof produced by [`prove_multiset_check`](Self::prove_multiset_check).
+    /// `final_eval` is the check polynomial's multilinear extension evaluated
+    /// at the sum-check's challenge point, obtained from a commitment
+    /// opening; the check only holds for a vanishing polynomial, so the
+    /// claimed sum itself must also be zero.
+    pub(crate) fn verify_multiset_check(
+        proof: &SumCheckProof,
+        transcript: &mut impl Transcript,
+        final_eval: Fr,
+    ) -> Result<bool, SumCheckError> {
+        if !proof.claimed_sum.is_zero() {
+            return Ok(false);
+        }
+        sumcheck::verify(proof, transcript, final_eval)?;
+        Ok(true)
     }
 }
 
 struct TableCompression {
     width: usize,
-    poseidon: PoseidonHasher,
+    poseidon: PoseidonHash,
     compression_matrix: Vec<Vec<Fr>>,
 }
 
@@ -136,7 +649,7 @@ impl TableCompression {
         let matrix = Self::generate_compression_matrix(width);
         TableCompression {
             width,
-            poseidon: PoseidonHasher::new(),
+            poseidon: PoseidonHash::new(),
             compression_matrix: matrix,
         }
     }
@@ -144,7 +657,7 @@ impl TableCompression {
     fn generate_compression_matrix(width: usize) -> Vec<Vec<Fr>> {
         let mut matrix = vec![vec![Fr::zero(); width]; width];
         let mut rng = rand::thread_rng();
-        
+
         // Generate random invertible matrix
         loop {
             for i in 0..width {
@@ -159,11 +672,40 @@ impl TableCompression {
         matrix
     }
 
-    fn compress(&self, input: &[Fr]) -> Fr {
+    /// Gaussian elimination down to row-echelon form, checking every pivot
+    /// is nonzero — the standard "determinant nonzero" test without
+    /// actually computing the determinant.
+    fn is_invertible(matrix: &[Vec<Fr>]) -> bool {
+        let n = matrix.len();
+        let mut m = matrix.to_vec();
+        for col in 0..n {
+            let pivot = (col..n).find(|&r| !m[r][col].is_zero());
+            let pivot = match pivot {
+                Some(p) => p,
+                None => return false,
+            };
+            m.swap(col, pivot);
+            let inv = m[col][col].inverse().expect("pivot is nonzero by construction");
+            for row in (col + 1)..n {
+                let factor = m[row][col] * inv;
+                for c in col..n {
+                    let term = m[col][c] * factor;
+                    m[row][c] -= term;
+                }
+            }
+        }
+        true
+    }
+
+    fn compress(&mut self, input: &[Fr]) -> Fr {
+        let width = self.width.max(1);
+        let mut padded = input.to_vec();
+        padded.resize(width, Fr::zero());
+
         let mut result = Fr::zero();
-        for (row, &value) in self.compression_matrix.iter().zip(input) {
+        for (row, &value) in self.compression_matrix.iter().zip(&padded) {
             let mut term = Fr::zero();
-            for (&coeff, &base) in row.iter().zip(input) {
+            for (&coeff, &base) in row.iter().zip(&padded) {
                 term += coeff * base;
             }
             result += term * value;
@@ -176,4 +718,27 @@ enum MultisetCheck {
     Frequency(Polynomial),
     Permutation(Polynomial),
     Custom(Box<dyn Fn(&[Fr]) -> Polynomial>),
-} 
\ No newline at end of file
+}
+
+impl MultisetCheck {
+    /// Lays the check polynomial's coefficients over an evaluation domain
+    /// sized to the table and reinterprets the result as a multilinear
+    /// polynomial's hypercube evaluations (the domain size is already a
+    /// power of two, so no repacking is needed). `Custom` checks carry no
+    /// polynomial until applied to a row, so they have no domain-wide
+    /// multilinear extension to hand to sum-check.
+    fn to_multilinear(&self, table_size: usize) -> Option<MultilinearPolynomial> {
+        let poly = match self {
+            MultisetCheck::Frequency(poly) | MultisetCheck::Permutation(poly) => poly,
+            MultisetCheck::Custom(_) => return None,
+        };
+
+        let domain = EvaluationDomain::new(table_size.max(1)).ok()?;
+        let m = domain.size();
+        let mut evals = poly.coefficients().to_vec();
+        evals.resize(m, Fr::zero());
+        domain.fft(&mut evals);
+
+        Some(MultilinearPolynomial::new(evals))
+    }
+}
\ No newline at end of file
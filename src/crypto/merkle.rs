@@ -1,5 +1,145 @@
 use super::hash::PoseidonHash;
 use crate::field::Fr;
+use std::collections::HashMap;
+
+/// Depth of the sparse Merkle tree backing `SparseMerkleTree`/`Memory`: one
+/// level per bit of a `u64` address, so every address has a unique root-to-
+/// leaf path without the dense `MerkleTree` below ever materializing a
+/// `2^64`-leaf array.
+pub const SPARSE_TREE_DEPTH: usize = 64;
+
+/// Precomputes `hashes[level]`, the hash of an all-default (all-zero-leaf)
+/// subtree of height `level`: `hashes[0]` is the default leaf itself,
+/// `hashes[level] = hasher.hash(&[hashes[level-1], hashes[level-1]])`. A
+/// sparse tree only ever needs to store the `O(touched addresses * depth)`
+/// nodes that differ from this default, reading every other node straight
+/// out of this table instead.
+fn empty_node_hashes(hasher: &mut PoseidonHash, depth: usize) -> Vec<Fr> {
+    let mut hashes = Vec::with_capacity(depth + 1);
+    hashes.push(Fr::zero());
+    for level in 1..=depth {
+        let prev = hashes[level - 1];
+        hashes.push(hasher.hash(&[prev, prev]));
+    }
+    hashes
+}
+
+/// A Merkle proof over [`SparseMerkleTree`]: one sibling per level, ordered
+/// from the leaf up to the root, exactly like [`MerkleProof`] but spanning
+/// [`SPARSE_TREE_DEPTH`] levels instead of `log2(leaves.len())`.
+#[derive(Clone, Debug)]
+pub struct SparseMerkleProof {
+    pub siblings: Vec<Fr>,
+}
+
+impl SparseMerkleProof {
+    /// Recomputes the root `leaf` implies under this proof's siblings,
+    /// walking `address`'s bits from the leaf level upward (bit `0` selects
+    /// left/right at the leaf's parent, same convention `MerkleProof::verify`'s
+    /// `current_index % 2` uses for the dense tree).
+    pub fn recompute_root(&self, address: u64, leaf: Fr, hasher: &mut PoseidonHash) -> Fr {
+        let mut current = leaf;
+        let mut index = address;
+        for &sibling in &self.siblings {
+            let (left, right) = if index & 1 == 0 {
+                (current, sibling)
+            } else {
+                (sibling, current)
+            };
+            current = hasher.hash(&[left, right]);
+            index >>= 1;
+        }
+        current
+    }
+
+    pub fn verify(&self, root: Fr, address: u64, leaf: Fr, hasher: &mut PoseidonHash) -> bool {
+        self.recompute_root(address, leaf, hasher) == root
+    }
+}
+
+/// A sparse Merkle tree over the full `u64` address space, for committing to
+/// `Memory`'s `HashMap<u64,u64>` without the dense `MerkleTree`'s `O(2^64)`
+/// `levels` array. Every address not explicitly touched reads as the default
+/// leaf `Fr::zero()`, and the subtree hashes above it collapse to
+/// [`empty_node_hashes`]'s precomputed table; only nodes on a path that's
+/// actually been written are ever stored.
+#[derive(Debug)]
+pub struct SparseMerkleTree {
+    hasher: PoseidonHash,
+    empty_hashes: Vec<Fr>,
+    /// `(level, index)` -> node hash, for every node that differs from its
+    /// level's default. Level `0` holds leaves (indexed by address), level
+    /// `SPARSE_TREE_DEPTH` holds only the root at index `0`.
+    nodes: HashMap<(usize, u64), Fr>,
+}
+
+impl SparseMerkleTree {
+    pub fn new() -> Self {
+        let mut hasher = PoseidonHash::new();
+        let empty_hashes = empty_node_hashes(&mut hasher, SPARSE_TREE_DEPTH);
+        SparseMerkleTree {
+            hasher,
+            empty_hashes,
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn node(&self, level: usize, index: u64) -> Fr {
+        self.nodes
+            .get(&(level, index))
+            .copied()
+            .unwrap_or(self.empty_hashes[level])
+    }
+
+    pub fn root(&self) -> Fr {
+        self.node(SPARSE_TREE_DEPTH, 0)
+    }
+
+    /// The current leaf hash at `address` (`Fr::zero()` if never written).
+    pub fn leaf(&self, address: u64) -> Fr {
+        self.node(0, address)
+    }
+
+    /// Writes `value` to `address`'s leaf and recomputes every node on the
+    /// path up to the root, touching exactly `SPARSE_TREE_DEPTH` nodes no
+    /// matter how large the address space is.
+    pub fn update(&mut self, address: u64, value: Fr) -> Fr {
+        self.nodes.insert((0, address), value);
+
+        let mut index = address;
+        for level in 0..SPARSE_TREE_DEPTH {
+            let sibling_index = index ^ 1;
+            let (left, right) = if index & 1 == 0 {
+                (self.node(level, index), self.node(level, sibling_index))
+            } else {
+                (self.node(level, sibling_index), self.node(level, index))
+            };
+            let parent = self.hasher.hash(&[left, right]);
+            index >>= 1;
+            self.nodes.insert((level + 1, index), parent);
+        }
+
+        self.root()
+    }
+
+    /// The Merkle proof for `address`'s current leaf.
+    pub fn prove(&self, address: u64) -> SparseMerkleProof {
+        let mut siblings = Vec::with_capacity(SPARSE_TREE_DEPTH);
+        let mut index = address;
+        for level in 0..SPARSE_TREE_DEPTH {
+            siblings.push(self.node(level, index ^ 1));
+            index >>= 1;
+        }
+        SparseMerkleProof { siblings }
+    }
+
+    /// Generates one proof per address in `addresses` against the tree's
+    /// *current* state, for batching every address an instruction touches
+    /// into a single call instead of one `prove` invocation per address.
+    pub fn prove_batch(&self, addresses: &[u64]) -> Vec<(u64, SparseMerkleProof)> {
+        addresses.iter().map(|&address| (address, self.prove(address))).collect()
+    }
+}
 
 pub struct MerkleTree {
     levels: Vec<Vec<Fr>>,
@@ -0,0 +1,177 @@
+use super::commitment::commit_msm;
+use super::hash::PoseidonHash;
+use super::sumcheck::MultilinearPolynomial;
+use crate::field::Fr;
+
+/// Multilinear KZG opening proof: one "witness" commitment per variable,
+/// from successively dividing `f(X) - f(z)` the way
+/// [`MultilinearKZG::open`] folds the evaluation table. The claimed value
+/// is not stored here; `open` returns it alongside this proof.
+#[derive(Clone, Debug)]
+pub struct MultilinearOpeningProof {
+    pub witness_commitments: Vec<Fr>,
+}
+
+/// The hypercube-indexed Lagrange basis `[L_x(tau)]` for every `x` in
+/// `{0,1}^tau.len()`, ordered to match `MultilinearPolynomial`'s
+/// MSB-first variable convention: `tau[0]` controls the most significant
+/// bit of the index, `tau[tau.len()-1]` the least significant, so
+/// `basis[i]` lines up with `MultilinearPolynomial::evaluations()[i]`.
+fn lagrange_basis(tau: &[Fr]) -> Vec<Fr> {
+    let mut basis = vec![Fr::one()];
+    for &t in tau.iter().rev() {
+        let mut next = Vec::with_capacity(basis.len() * 2);
+        for &b in &basis {
+            next.push(b * (Fr::one() - t));
+        }
+        for &b in &basis {
+            next.push(b * t);
+        }
+        basis = next;
+    }
+    basis
+}
+
+/// Multilinear analogue of a univariate KZG commitment, over the boolean
+/// hypercube `{0,1}^n` rather than a univariate evaluation domain:
+/// `ProofGenerator`'s witness and lookup polynomials are naturally
+/// multilinear (one evaluation per trace row), so committing to them
+/// through `KZGCommitment`'s univariate encoding would force an
+/// arbitrary variable ordering with no structural meaning.
+///
+/// `tau = (tau_1, ..., tau_n)` is the secret multivariate evaluation
+/// point; `lagrange_srs[x]` is `[L_x(tau)]`, the secret evaluation of
+/// hypercube point `x`'s Lagrange basis polynomial. Since this crate
+/// represents "group elements" as bare `Fr` values everywhere (see
+/// `commit_msm`), a commitment reduces to the linear combination
+/// `commit_msm(evals, lagrange_srs)` — which is exactly `f`'s
+/// multilinear extension evaluated at `tau`.
+pub struct MultilinearKZG {
+    tau: Vec<Fr>,
+    lagrange_srs: Vec<Fr>,
+    num_vars: usize,
+}
+
+impl MultilinearKZG {
+    pub fn new(num_vars: usize) -> Self {
+        let mut rng = rand::thread_rng();
+        let tau: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+        Self::from_tau(tau)
+    }
+
+    /// Builds a `MultilinearKZG` from an externally-supplied secret point
+    /// `tau` — e.g. one derived from a shared
+    /// `crate::crypto::universal_srs::UniversalSrs` via
+    /// `UniversalSrs::specialize_multilinear` — instead of `new`'s
+    /// independently sampled one.
+    pub fn from_tau(tau: Vec<Fr>) -> Self {
+        let num_vars = tau.len();
+        let lagrange_srs = lagrange_basis(&tau);
+        MultilinearKZG { tau, lagrange_srs, num_vars }
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// `C = sum_x f(x) * [L_x(tau)]`.
+    pub fn commit(&self, poly: &MultilinearPolynomial) -> Fr {
+        assert_eq!(poly.num_vars(), self.num_vars);
+        commit_msm(poly.evaluations(), &self.lagrange_srs)
+    }
+
+    /// Opens `poly` at `point = (z_1, ..., z_n)`: decomposes
+    /// `f(X) - f(z) = sum_i (X_i - z_i) * q_i(X)` by successively folding
+    /// the evaluation table the same way `MultilinearPolynomial::fix_variable`
+    /// does, reading off each step's `q_i` as half the table's
+    /// before-fold difference before collapsing it with `z_i`.
+    pub fn open(&self, poly: &MultilinearPolynomial, point: &[Fr]) -> (Fr, MultilinearOpeningProof) {
+        assert_eq!(poly.num_vars(), self.num_vars);
+        assert_eq!(point.len(), self.num_vars);
+
+        let mut table = poly.evaluations().to_vec();
+        let mut witness_commitments = Vec::with_capacity(self.num_vars);
+
+        for (i, &z_i) in point.iter().enumerate() {
+            let half = table.len() / 2;
+            let q_evals: Vec<Fr> = (0..half).map(|k| table[half + k] - table[k]).collect();
+            let suffix_srs = lagrange_basis(&self.tau[i + 1..]);
+            witness_commitments.push(commit_msm(&q_evals, &suffix_srs));
+
+            table = (0..half)
+                .map(|k| table[k] + z_i * (table[half + k] - table[k]))
+                .collect();
+        }
+
+        (table[0], MultilinearOpeningProof { witness_commitments })
+    }
+
+    /// `e(C - g^v, h) = Pi_i e([q_i], h^{tau_i - z_i})` reduces, under this
+    /// crate's Fr-valued stand-in for a pairing-friendly group, to the
+    /// linear identity checked below — a real deployment would check it
+    /// via an actual pairing against a public verifying key instead of
+    /// `self` holding the secret `tau` on both the proving and verifying
+    /// side, the same simplification `ProofSystem::verify` already makes
+    /// for its own challenge-binding check.
+    pub fn verify(&self, commitment: Fr, point: &[Fr], value: Fr, proof: &MultilinearOpeningProof) -> bool {
+        if point.len() != self.num_vars || proof.witness_commitments.len() != self.num_vars {
+            return false;
+        }
+
+        let rhs = proof
+            .witness_commitments
+            .iter()
+            .zip(point.iter())
+            .enumerate()
+            .fold(Fr::zero(), |acc, (i, (&q_commitment, &z_i))| {
+                acc + q_commitment * (self.tau[i] - z_i)
+            });
+
+        commitment - value == rhs
+    }
+
+    /// Batches `polys`' openings at the same `point` into a single proof:
+    /// random-linear-combines both the claimed values' commitments and
+    /// the per-variable witness commitments with transcript challenge `r`
+    /// (one running `PoseidonHash`, the same ad hoc transcript
+    /// `ProofSystem::create_proof` uses), so a verifier checks one
+    /// opening instead of `polys.len()`.
+    pub fn open_batch(&self, polys: &[MultilinearPolynomial], point: &[Fr]) -> (Vec<Fr>, Fr, MultilinearOpeningProof) {
+        let values: Vec<Fr> = polys.iter().map(|poly| poly.evaluate(point)).collect();
+        let r = PoseidonHash::new().hash(&values);
+
+        let mut combined = vec![Fr::zero(); 1 << self.num_vars];
+        let mut power = Fr::one();
+        for poly in polys {
+            for (slot, &eval) in combined.iter_mut().zip(poly.evaluations()) {
+                *slot += power * eval;
+            }
+            power *= r;
+        }
+
+        let (combined_value, proof) = self.open(&MultilinearPolynomial::new(combined), point);
+        (values, combined_value, proof)
+    }
+
+    /// Verifies a batched opening produced by [`Self::open_batch`]:
+    /// re-derives `r` from `values` and checks the same random-linear
+    /// combination of `commitments` against `proof`.
+    pub fn verify_batch(
+        &self,
+        commitments: &[Fr],
+        point: &[Fr],
+        values: &[Fr],
+        combined_value: Fr,
+        proof: &MultilinearOpeningProof,
+    ) -> bool {
+        let r = PoseidonHash::new().hash(values);
+        let mut power = Fr::one();
+        let mut combined_commitment = Fr::zero();
+        for &commitment in commitments {
+            combined_commitment += power * commitment;
+            power *= r;
+        }
+
+        self.verify(combined_commitment, point, combined_value, proof)
+    }
+}
@@ -1,36 +1,40 @@
+pub mod generator;
+pub mod solidity;
+
 use super::{PedersenCommitment, PoseidonHash, Polynomial};
 use crate::field::Fr;
 
-pub struct ProofSystem {
-    commitment: PedersenCommitment,
-    hasher: PoseidonHash,
-}
+pub struct ProofSystem;
 
 impl ProofSystem {
     pub fn new() -> Self {
-        ProofSystem {
-            commitment: PedersenCommitment::new(32),
-            hasher: PoseidonHash::new(),
-        }
+        ProofSystem
     }
 
     pub fn create_proof(&mut self, witness: &[Fr], public_inputs: &[Fr]) -> Proof {
         // Generate polynomials from witness and constraints
         let witness_poly = Polynomial::new(witness.to_vec());
-        
-        // Create commitments
-        let witness_commitment = self.commitment.commit(witness);
-        
+
+        // Create commitments, sized to this call's witness rather than a
+        // fixed generator count, the same "size the key to the data" idiom
+        // `crate::crypto::folding::fold`'s cross-term commitment uses.
+        let witness_commitment = PedersenCommitment::new(witness.len()).commit(witness);
+
         // Generate proof components
         let mut transcript = Vec::new();
         transcript.extend_from_slice(public_inputs);
         transcript.push(witness_commitment);
-        
-        let challenge = self.hasher.hash(&transcript);
-        
+
+        // A fresh hasher per call, as `UniversalSrs` and `MultilinearKZG`
+        // also use — `PoseidonHash`'s
+        // capacity lane carries state across calls on the same instance, so
+        // reusing one would make `verify`'s challenge depend on how many
+        // proofs were created on this `ProofSystem` before it.
+        let challenge = PoseidonHash::new().hash(&transcript);
+
         // Evaluate polynomials at challenge point
         let evaluation = witness_poly.evaluate(challenge);
-        
+
         Proof {
             witness_commitment,
             evaluation,
@@ -43,16 +47,16 @@ impl ProofSystem {
         let mut transcript = Vec::new();
         transcript.extend_from_slice(public_inputs);
         transcript.push(proof.witness_commitment);
-        
-        let challenge = self.hasher.hash(&transcript);
-        
+
+        let challenge = PoseidonHash::new().hash(&transcript);
+
         // Verify proof components
         challenge == proof.challenge
     }
 }
 
 pub struct Proof {
-    witness_commitment: Fr,
-    evaluation: Fr,
-    challenge: Fr,
+    pub witness_commitment: Fr,
+    pub evaluation: Fr,
+    pub challenge: Fr,
 }
\ No newline at end of file
@@ -1,62 +1,249 @@
 use crate::field::Fr;
-use crate::crypto::polynomial::*;
 use crate::crypto::commitment::*;
-use crate::crypto::lookup::*;
+use crate::crypto::lookup::table::{self as lookup_table, GrandProductProof};
+use crate::crypto::merkle::SparseMerkleProof;
+use crate::crypto::multilinear_kzg::{MultilinearKZG, MultilinearOpeningProof};
+use crate::crypto::sumcheck::{self, MultilinearPolynomial, SumCheckProof};
+use crate::crypto::universal_srs::UniversalSrs;
+use crate::instructions::trap::{ExecutionWitness, Trap, TrapHandler};
 use rayon::prelude::*;
 
-/// Advanced proof generator for Jolt zkVM
-pub struct ProofGenerator {
-    // Polynomial commitment schemes
-    poly_commit: PolynomialCommitment,
-    kzg_commit: KZGCommitment,
+/// A Fiat–Shamir transcript `ProofGenerator` draws every challenge from.
+/// Parameterizing over `T: Transcript` instead of hardcoding one sponge
+/// lets `prove_instruction` share a single running transcript across its
+/// poly-commitment, lookup, and range sub-proofs while still letting a
+/// caller pick the hash an on-chain verifier actually implements:
+/// `PoseidonTranscript` for native verification, `KeccakTranscript` to
+/// match a Solidity `keccak256` verifier like the ones
+/// `crate::crypto::proof_solidity`/`crate::crypto::solidity_verifier`
+/// generate.
+pub trait Transcript {
+    fn new() -> Self;
+    fn append_scalar(&mut self, label: &str, value: &Fr);
+    fn append_commitment(&mut self, label: &str, commitment: &Fr);
+    fn challenge_scalar(&mut self, label: &str) -> Fr;
+}
+
+fn label_to_fr(label: &str) -> Fr {
+    Fr::from(label.bytes().fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64)))
+}
+
+/// The crate's native sponge transcript: every append folds `label` and
+/// `value` into a running Poseidon state, and every challenge folds in
+/// `label` then squeezes — the same running-state idiom
+/// `crate::crypto::lookup::proof::PoseidonTranscriptHasher` already uses,
+/// just exposed through the richer `Transcript` interface `ProofGenerator`
+/// needs (distinct append methods for scalars vs. commitments, even
+/// though both fold into the state the same way).
+pub struct PoseidonTranscript {
+    state: Fr,
+    hasher: crate::crypto::PoseidonHash,
+}
+
+impl Transcript for PoseidonTranscript {
+    fn new() -> Self {
+        PoseidonTranscript {
+            state: Fr::zero(),
+            hasher: crate::crypto::PoseidonHash::new(),
+        }
+    }
+
+    fn append_scalar(&mut self, label: &str, value: &Fr) {
+        // `PoseidonHash::hash` reads two inputs, so `label` and `value`
+        // are folded in with one call each.
+        self.state = self.hasher.hash(&[self.state, label_to_fr(label)]);
+        self.state = self.hasher.hash(&[self.state, *value]);
+    }
+
+    fn append_commitment(&mut self, label: &str, commitment: &Fr) {
+        self.append_scalar(label, commitment);
+    }
+
+    fn challenge_scalar(&mut self, label: &str) -> Fr {
+        self.state = self.hasher.hash(&[self.state, label_to_fr(label)]);
+        self.state
+    }
+}
+
+/// A transcript an EVM `keccak256`-based verifier can replay: every
+/// append/challenge absorbs `label`'s bytes and `value`'s canonical
+/// big-endian `Fr` encoding, the same `abi.encodePacked`-compatible layout
+/// `crate::crypto::proof_solidity::export_calldata` already uses for
+/// words it hands to a contract. The absorb/squeeze step itself is a
+/// placeholder digest, not yet byte-level Keccak-f[1600] — the same
+/// honesty `crate::crypto::lookup::proof::Keccak256TranscriptHasher`'s own
+/// doc comment carries — so only the permutation, not the transcript's
+/// encoding or call shape, needs swapping in once a real implementation
+/// lands.
+pub struct KeccakTranscript {
+    state: [u8; 32],
+}
+
+impl KeccakTranscript {
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        for (i, b) in bytes.iter().enumerate() {
+            self.state[i % 32] ^= b;
+        }
+        self.state.rotate_left(1);
+    }
+
+    fn canonical_be_bytes(value: &Fr) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[24..].copy_from_slice(&value.to_u64().to_be_bytes());
+        bytes
+    }
+}
+
+impl Transcript for KeccakTranscript {
+    fn new() -> Self {
+        KeccakTranscript { state: [0u8; 32] }
+    }
+
+    fn append_scalar(&mut self, label: &str, value: &Fr) {
+        self.absorb_bytes(label.as_bytes());
+        self.absorb_bytes(&Self::canonical_be_bytes(value));
+    }
+
+    fn append_commitment(&mut self, label: &str, commitment: &Fr) {
+        self.append_scalar(label, commitment);
+    }
+
+    fn challenge_scalar(&mut self, label: &str) -> Fr {
+        self.absorb_bytes(label.as_bytes());
+        let mut limb = [0u8; 8];
+        limb.copy_from_slice(&self.state[24..32]);
+        Fr::from(u64::from_be_bytes(limb))
+    }
+}
+
+/// Advanced proof generator for Jolt zkVM, generic over the Fiat–Shamir
+/// transcript `T` every sub-proof below draws its challenges from.
+/// Defaults to [`PoseidonTranscript`] so existing callers that don't care
+/// which backend they get don't need a turbofish.
+pub struct ProofGenerator<T: Transcript = PoseidonTranscript> {
+    // Polynomial commitment schemes. Both are `PolyCommitment`'s flexible
+    // "up to a generous max degree" key (see its own doc comment) rather
+    // than separate univariate-KZG/generic-polynomial schemes: this crate
+    // has no pairing group to build a real KZG over, so `kzg_commit`
+    // stands in for one the same way `KeccakTranscript`'s digest stands in
+    // for byte-level Keccak.
+    poly_commit: PolyCommitment,
+    kzg_commit: PolyCommitment,
     pedersen_commit: PedersenCommitment,
-    
+
     // Lookup table generators
     table_generators: Vec<TableGenerator>,
-    
-    // Challenge generators
-    challenge_gen: FiatShamirTranscript,
-    
+
+    // Multilinear commitment scheme for witness/lookup polynomials given
+    // as hypercube evaluations, alongside `kzg_commit`'s univariate
+    // encoding
+    multilinear_kzg: MultilinearKZG,
+
+    // The shared powers-of-tau setup `pedersen_commit` and
+    // `multilinear_kzg` were both specialized from, kept around so a
+    // later instruction needing a different degree can re-specialize
+    // without a fresh per-degree setup
+    universal_srs: UniversalSrs,
+
+    // Fiat-Shamir transcript shared across every sub-proof in a single
+    // `prove_instruction` call
+    challenge_gen: T,
+
+    // The execution trace's memory, committed to via a sparse Merkle root
+    // `prove_memory_accesses` replays pre/post state against.
+    memory: crate::memory::Memory,
+
+    // Trap-transition prover for steps that fault instead of executing
+    // normally; `generate_trap_proof` calls it directly, without touching
+    // the transcript or the commitment schemes.
+    trap_handler: TrapHandler,
+
     // Optimization parameters
     batch_size: usize,
     parallel_proofs: bool,
 }
 
-impl ProofGenerator {
+/// The hypercube dimension `MultilinearKZG` needs to cover a witness
+/// trace of up to `poly_degree` rows: the smallest `n` with `2^n >=
+/// poly_degree`.
+fn num_vars_for_degree(poly_degree: usize) -> usize {
+    poly_degree.max(1).next_power_of_two().trailing_zeros() as usize
+}
+
+impl<T: Transcript> ProofGenerator<T> {
     pub fn new(security_params: SecurityParameters) -> Self {
+        // One universal powers-of-tau setup, specialized per commitment
+        // scheme below, instead of each scheme sampling its own secret
+        // independently.
+        let universal_degree = security_params
+            .max_degree
+            .max(security_params.num_generators)
+            .max(1 << num_vars_for_degree(security_params.poly_degree));
+        let universal_srs = UniversalSrs::setup(universal_degree);
+
+        let pedersen_srs = universal_srs.specialize(security_params.num_generators - 1);
+        let num_vars = num_vars_for_degree(security_params.poly_degree);
+        let ml_tau = universal_srs.specialize_multilinear(num_vars);
+
         Self {
-            poly_commit: PolynomialCommitment::new(security_params.poly_degree),
-            kzg_commit: KZGCommitment::new(security_params.max_degree),
-            pedersen_commit: PedersenCommitment::new(security_params.num_generators),
+            poly_commit: PolyCommitment::new(),
+            kzg_commit: PolyCommitment::new(),
+            pedersen_commit: PedersenCommitment::from_srs(pedersen_srs.powers_g1),
             table_generators: Vec::new(),
-            challenge_gen: FiatShamirTranscript::new(),
+            multilinear_kzg: MultilinearKZG::from_tau(ml_tau),
+            universal_srs,
+            challenge_gen: T::new(),
+            memory: crate::memory::Memory::new(64),
+            trap_handler: TrapHandler::new(),
             batch_size: security_params.batch_size,
             parallel_proofs: security_params.enable_parallel,
         }
     }
 
+    /// Opens commitments to several different instructions' witness
+    /// polynomials at one common challenge point in a single aggregated
+    /// proof, rather than one `generate_poly_commitments` batch proof per
+    /// instruction (that method's own `generate_batch_opening_proofs`
+    /// call only ever batches one instruction's polynomials): flattens
+    /// every instruction's polynomials into one `MultilinearKZG::open_batch`
+    /// call, so a verifier checks a single opening no matter how many
+    /// instructions contributed to it.
+    pub fn generate_cross_instruction_batch_proof(
+        &self,
+        per_instruction_polys: &[Vec<MultilinearPolynomial>],
+        point: &[Fr],
+    ) -> (Vec<Fr>, Fr, MultilinearOpeningProof) {
+        let all_polys: Vec<MultilinearPolynomial> = per_instruction_polys
+            .iter()
+            .flat_map(|polys| polys.iter().cloned())
+            .collect();
+        self.multilinear_kzg.open_batch(&all_polys, point)
+    }
+
     /// Generate complete proof for instruction execution
-    pub fn prove_instruction(&mut self, 
+    pub fn prove_instruction(&mut self,
         instruction: &Instruction,
         witness: &ExecutionWitness,
         aux_data: &AuxiliaryData
     ) -> Result<CompleteProof, ProofError> {
+        self.challenge_gen.append_scalar("opcode", &Fr::from(instruction.opcode as u64));
+
         // 1. Generate polynomial commitments
         let poly_commitments = self.generate_poly_commitments(witness)?;
-        
+
         // 2. Generate lookup proofs
         let lookup_proofs = if self.parallel_proofs {
             self.generate_parallel_lookup_proofs(instruction, witness)?
         } else {
             self.generate_lookup_proofs(instruction, witness)?
         };
-        
+
         // 3. Generate state transition proofs
         let state_proofs = self.prove_state_transitions(witness, aux_data)?;
-        
+
         // 4. Generate range proofs
         let range_proofs = self.generate_range_proofs(witness)?;
-        
+
         // 5. Combine all proofs
         self.combine_proofs(
             poly_commitments,
@@ -66,27 +253,109 @@ impl ProofGenerator {
         )
     }
 
+    /// Commits a witness or lookup polynomial given as `2^n` hypercube
+    /// evaluations through [`MultilinearKZG`] instead of `kzg_commit`'s
+    /// univariate encoding — the representation `construct_witness_polynomials`
+    /// naturally produces one evaluation per trace row in, with no
+    /// arbitrary univariate variable ordering to choose.
+    fn commit_multilinear_witness(&self, evaluations: Vec<Fr>) -> (Fr, MultilinearPolynomial) {
+        let poly = MultilinearPolynomial::new(evaluations);
+        (self.multilinear_kzg.commit(&poly), poly)
+    }
+
+    /// Opens a witness polynomial committed via [`Self::commit_multilinear_witness`]
+    /// at `point`, appending the commitment to the transcript first so the
+    /// evaluation point itself is bound to it.
+    fn open_multilinear_witness(
+        &mut self,
+        commitment: Fr,
+        poly: &MultilinearPolynomial,
+        point: &[Fr],
+    ) -> (Fr, MultilinearOpeningProof) {
+        self.challenge_gen.append_commitment("multilinear_witness_commitment", &commitment);
+        self.multilinear_kzg.open(poly, point)
+    }
+
+    /// Lays a step's addressable fields out as one multilinear polynomial
+    /// over the boolean hypercube (padded to the next power of two the
+    /// same way [`Self::prove_multiset_equality`] pads its LogUp
+    /// denominators), so the rest of `generate_poly_commitments` has
+    /// something to commit to and open.
+    fn construct_witness_polynomials(&self, witness: &ExecutionWitness) -> Result<Vec<MultilinearPolynomial>, ProofError> {
+        let row = vec![
+            Fr::from(witness.pc),
+            Fr::from(witness.rd as u64),
+            Fr::from(witness.rd_value),
+            Fr::from(witness.mem_addr),
+            Fr::from(witness.mem_value),
+            Fr::from(witness.divisor),
+        ];
+        Ok(vec![MultilinearPolynomial::new(lookup_table::pad_with_zero(row))])
+    }
+
+    /// Evaluates each witness polynomial at `eval_point` repeated across
+    /// every hypercube variable — a single Fiat-Shamir challenge standing
+    /// in for a full evaluation point, the same simplification
+    /// [`Self::generate_cross_instruction_batch_proof`]'s caller makes
+    /// when it hands every instruction the same `point`.
+    fn generate_evaluation_proofs(
+        &self,
+        witness_polys: &[MultilinearPolynomial],
+        eval_point: Fr,
+    ) -> Result<Vec<Fr>, ProofError> {
+        Ok(witness_polys
+            .iter()
+            .map(|poly| poly.evaluate(&vec![eval_point; poly.num_vars()]))
+            .collect())
+    }
+
+    /// Aggregates every witness polynomial's commitment and claimed
+    /// evaluation into one batched opening via a random linear
+    /// combination — a placeholder for a real batched KZG opening proof,
+    /// the same honesty [`KeccakTranscript`]'s doc comment carries about
+    /// its own not-yet-real digest.
+    fn generate_batch_opening_proofs(
+        &mut self,
+        witness_polys: &[MultilinearPolynomial],
+        eval_proofs: &[Fr],
+    ) -> Result<Fr, ProofError> {
+        let batch_challenge = self.challenge_gen.challenge_scalar("batch_opening");
+        let mut acc = Fr::zero();
+        let mut power = Fr::one();
+        for (poly, &eval) in witness_polys.iter().zip(eval_proofs.iter()) {
+            let commitment = self.kzg_commit.commit(poly.evaluations());
+            acc += power * (commitment + eval);
+            power *= batch_challenge;
+        }
+        Ok(acc)
+    }
+
     /// Generate polynomial commitments
-    fn generate_poly_commitments(&self, witness: &ExecutionWitness) 
-        -> Result<PolynomialCommitments, ProofError> 
+    fn generate_poly_commitments(&mut self, witness: &ExecutionWitness)
+        -> Result<PolynomialCommitments, ProofError>
     {
         // 1. Construct witness polynomials
         let witness_polys = self.construct_witness_polynomials(witness)?;
-        
+
         // 2. Generate KZG commitments
-        let kzg_commits = witness_polys.par_iter().map(|poly| {
-            self.kzg_commit.commit(poly)
+        let kzg_commits: Vec<Fr> = witness_polys.par_iter().map(|poly| {
+            self.kzg_commit.commit(poly.evaluations())
         }).collect();
-        
+
+        for commitment in &kzg_commits {
+            self.challenge_gen.append_commitment("witness_commitment", commitment);
+        }
+        let eval_point = self.challenge_gen.challenge_scalar("eval_point");
+
         // 3. Generate evaluation proofs
-        let eval_proofs = self.generate_evaluation_proofs(&witness_polys)?;
-        
+        let eval_proofs = self.generate_evaluation_proofs(&witness_polys, eval_point)?;
+
         // 4. Generate batch opening proofs
         let batch_proofs = self.generate_batch_opening_proofs(
             &witness_polys,
             &eval_proofs
         )?;
-        
+
         Ok(PolynomialCommitments {
             witness_commitments: kzg_commits,
             evaluation_proofs: eval_proofs,
@@ -94,26 +363,107 @@ impl ProofGenerator {
         })
     }
 
+    /// Lazily materializes `instruction.opcode`'s lookup table if this is
+    /// the first time it's been seen, keyed by opcode — unlike the
+    /// per-chunk [`crate::crypto::lookup::LookupSubtable`]s, which
+    /// `LassoLookup::new` allocates upfront.
+    fn ensure_table_generator(&mut self, opcode: u8) {
+        if !self.table_generators.iter().any(|g| g.opcode == opcode) {
+            self.table_generators.push(TableGenerator::for_opcode(opcode));
+        }
+    }
+
+    /// Commits to every lookup table materialized so far via a freshly
+    /// sized Pedersen key per table, the same "size the key to the data"
+    /// idiom [`crate::crypto::folding::fold`]'s cross-term commitment uses
+    /// rather than reusing `self.pedersen_commit`'s fixed generator count.
+    fn commit_to_lookup_tables(&mut self, instruction: &Instruction) -> Result<Vec<Fr>, ProofError> {
+        self.ensure_table_generator(instruction.opcode);
+        Ok(self
+            .table_generators
+            .iter()
+            .map(|generator| PedersenCommitment::new(generator.entries.len()).commit(&generator.entries))
+            .collect())
+    }
+
+    /// Commits to a claimed permutation of the witness's addressable
+    /// fields, each shifted by one shared Fiat-Shamir challenge — a
+    /// placeholder for a real permutation argument, the same role
+    /// `table_commit` alone plays for a full per-row opening throughout
+    /// this file.
+    fn generate_permutation_proofs(&mut self, witness: &ExecutionWitness) -> Result<Vec<Fr>, ProofError> {
+        let fields = [
+            Fr::from(witness.pc),
+            Fr::from(witness.rd_value),
+            Fr::from(witness.mem_addr),
+            Fr::from(witness.mem_value),
+        ];
+        let challenge = self.challenge_gen.challenge_scalar("permutation");
+        Ok(fields
+            .iter()
+            .map(|&field| PedersenCommitment::new(1).commit(&[field + challenge]))
+            .collect())
+    }
+
+    /// Parallel counterpart to [`Self::generate_lookup_proofs`]: table
+    /// commitments are produced independently via `rayon`, matching
+    /// `generate_poly_commitments`'s `par_iter` use for witness-polynomial
+    /// commitments, since each table's commitment doesn't depend on any
+    /// other table's.
+    fn generate_parallel_lookup_proofs(
+        &mut self,
+        instruction: &Instruction,
+        witness: &ExecutionWitness,
+    ) -> Result<LookupProofs, ProofError> {
+        self.ensure_table_generator(instruction.opcode);
+        let table_commits: Vec<Fr> = self
+            .table_generators
+            .par_iter()
+            .map(|generator| PedersenCommitment::new(generator.entries.len()).commit(&generator.entries))
+            .collect();
+        for commitment in &table_commits {
+            self.challenge_gen.append_commitment("table_commitment", commitment);
+        }
+
+        let perm_proofs = self.generate_permutation_proofs(witness)?;
+        let multiset_proofs = self.prove_multiset_equality(&table_commits, witness)?;
+        let grand_product_challenge = self.challenge_gen.challenge_scalar("grand_product");
+        let grand_product = self.prove_grand_product(&multiset_proofs, grand_product_challenge)?;
+
+        Ok(LookupProofs {
+            table_commitments: table_commits,
+            permutation_proofs: perm_proofs,
+            multiset_proofs,
+            grand_product,
+        })
+    }
+
     /// Generate lookup table proofs
-    fn generate_lookup_proofs(&self,
+    fn generate_lookup_proofs(&mut self,
         instruction: &Instruction,
         witness: &ExecutionWitness
     ) -> Result<LookupProofs, ProofError> {
         // 1. Generate table commitments
         let table_commits = self.commit_to_lookup_tables(instruction)?;
-        
+        for commitment in &table_commits {
+            self.challenge_gen.append_commitment("table_commitment", commitment);
+        }
+
         // 2. Generate permutation proofs
         let perm_proofs = self.generate_permutation_proofs(witness)?;
-        
+
         // 3. Generate multiset equality proofs
         let multiset_proofs = self.prove_multiset_equality(
             &table_commits,
             witness
         )?;
-        
-        // 4. Generate grand product arguments
-        let grand_product = self.prove_grand_product(&multiset_proofs)?;
-        
+
+        // 4. Generate grand product arguments, bound to the same
+        // transcript the multiset proofs were just folded into so a
+        // verifier re-derives the identical challenge.
+        let grand_product_challenge = self.challenge_gen.challenge_scalar("grand_product");
+        let grand_product = self.prove_grand_product(&multiset_proofs, grand_product_challenge)?;
+
         Ok(LookupProofs {
             table_commitments: table_commits,
             permutation_proofs: perm_proofs,
@@ -122,29 +472,193 @@ impl ProofGenerator {
         })
     }
 
+    /// Proves, per committed table, that the instruction's witness values
+    /// are a sub-multiset of that table via the linear-time LogUp sum-check
+    /// `crate::crypto::lookup::table::LookupTable::prove_frequency` already
+    /// runs: batch-invert `alpha + value` on both the witness side and the
+    /// table-commitment side, then discharge `sum 1/(alpha+a_i) ==
+    /// sum m_j/(alpha+t_j)` as a pair of sum-checks over the boolean
+    /// hypercube instead of requiring the verifier to re-sum every term
+    /// itself. `witness`'s four scalar fields stand in for the row this
+    /// instruction looks up against each table.
+    fn prove_multiset_equality(
+        &mut self,
+        table_commits: &[Fr],
+        witness: &ExecutionWitness,
+    ) -> Result<Vec<MultisetProof>, ProofError> {
+        let witness_values = [
+            Fr::from(witness.pc),
+            Fr::from(witness.rd_value),
+            Fr::from(witness.mem_addr),
+            Fr::from(witness.mem_value),
+        ];
+
+        table_commits
+            .iter()
+            .map(|&table_commit| {
+                self.challenge_gen.append_commitment("multiset_table", &table_commit);
+                let alpha = self.challenge_gen.challenge_scalar("logup_alpha");
+
+                let trace_inverses = crate::field::batch_inverse(
+                    &witness_values.iter().map(|&v| alpha + v).collect::<Vec<_>>(),
+                );
+                let trace_poly = MultilinearPolynomial::new(lookup_table::pad_with_zero(trace_inverses));
+
+                // The table side is represented by its single commitment,
+                // weighted by a multiplicity of one row: with no per-row
+                // openings wired in yet, `table_commit` itself stands in for
+                // the lone denominator this instruction's witness is checked
+                // against, the same placeholder `table_commit` already plays
+                // everywhere else in this file.
+                let table_inverse = (alpha + table_commit)
+                    .inverse()
+                    .ok_or(ProofError::LookupFailed)?;
+                let table_poly = MultilinearPolynomial::new(lookup_table::pad_with_zero(vec![table_inverse]));
+
+                let mut local_transcript = T::new();
+                let (trace_sumcheck, trace_r) = sumcheck::prove(&trace_poly, &mut local_transcript);
+                let (table_sumcheck, table_r) = sumcheck::prove(&table_poly, &mut local_transcript);
+
+                Ok(MultisetProof {
+                    trace_eval: trace_poly.evaluate(&trace_r),
+                    table_eval: table_poly.evaluate(&table_r),
+                    trace_sumcheck,
+                    table_sumcheck,
+                })
+            })
+            .collect()
+    }
+
+    /// Proves the grand-product (set-equality) half of the lookup argument:
+    /// each `MultisetProof`'s trace/table evaluations are folded into a
+    /// single running product via the same `eq`-weighted sum-check
+    /// `crate::crypto::lookup::table::prove_permutation` discharges for a
+    /// binary product tree, here applied to the flat vector of per-table
+    /// claims rather than a single table's rows.
+    fn prove_grand_product(
+        &mut self,
+        multiset_proofs: &[MultisetProof],
+        challenge: Fr,
+    ) -> Result<GrandProductProof, ProofError> {
+        let left_terms: Vec<Fr> = multiset_proofs
+            .iter()
+            .map(|proof| challenge + proof.trace_eval)
+            .collect();
+        let right_terms: Vec<Fr> = multiset_proofs
+            .iter()
+            .map(|proof| challenge + proof.table_eval)
+            .collect();
+
+        let mut local_transcript = T::new();
+        Ok(lookup_table::prove_permutation(&left_terms, &right_terms, &mut local_transcript))
+    }
+
+    /// Proves a single execution step's memory access by replaying it
+    /// against `self.memory`'s sparse Merkle tree: records the pre-state
+    /// leaf and its [`SparseMerkleProof`], performs the write, and records
+    /// the resulting root, so a verifier can check the pre-state proof
+    /// against the previous step's post-root, then recompute the post-root
+    /// itself from `written_value` without ever seeing the full memory.
+    fn prove_memory_accesses(&mut self, witness: &ExecutionWitness) -> Result<MemoryAccessProof, ProofError> {
+        let address = witness.mem_addr;
+        let written_value = Fr::from(witness.mem_value);
+
+        let pre_root = self.memory.root();
+        let pre_leaf = self.memory.tree().leaf(address);
+        let pre_proof = self.memory.tree().prove(address);
+
+        self.memory.write(address, witness.mem_value);
+        let post_root = self.memory.root();
+
+        self.challenge_gen.append_scalar("memory_pre_root", &pre_root);
+        self.challenge_gen.append_scalar("memory_post_root", &post_root);
+
+        Ok(MemoryAccessProof {
+            address,
+            pre_root,
+            pre_leaf,
+            pre_proof,
+            written_value,
+            post_root,
+        })
+    }
+
+    /// Commits to a step's register-file-visible state (`pc`, `rd`,
+    /// `rd_value`) via `self.poly_commit`, appending the result to the
+    /// transcript the same way [`Self::prove_memory_accesses`] appends
+    /// its pre/post memory roots.
+    fn commit_to_states(&mut self, witness: &ExecutionWitness) -> Result<Vec<Fr>, ProofError> {
+        let state = vec![
+            Fr::from(witness.pc),
+            Fr::from(witness.rd as u64),
+            Fr::from(witness.rd_value),
+        ];
+        let commitment = self.poly_commit.commit(&state);
+        self.challenge_gen.append_commitment("state_commitment", &commitment);
+        Ok(vec![commitment])
+    }
+
+    /// Folds `state_commits` through a Fiat-Shamir challenge into a single
+    /// value bound to `witness.divisor`, standing in for a real
+    /// transition-consistency circuit the way [`Self::prove_grand_product`]'s
+    /// flat product stands in for a full permutation argument.
+    fn prove_transition_consistency(
+        &mut self,
+        witness: &ExecutionWitness,
+        state_commits: &[Fr],
+    ) -> Result<Fr, ProofError> {
+        let challenge = self.challenge_gen.challenge_scalar("transition_consistency");
+        let folded = state_commits
+            .iter()
+            .fold(Fr::zero(), |acc, &commitment| acc * challenge + commitment);
+        Ok(folded + Fr::from(witness.divisor))
+    }
+
+    /// Checks `witness`'s destination register matches `aux_data`'s
+    /// claimed update before committing to the new value — the same
+    /// sanity check [`crate::crypto::memory_check::prove_memory_consistency`]
+    /// makes on its read/write timestamps before folding them into a
+    /// grand product.
+    fn prove_register_updates(
+        &mut self,
+        witness: &ExecutionWitness,
+        aux_data: &AuxiliaryData,
+    ) -> Result<Fr, ProofError> {
+        self.challenge_gen
+            .append_scalar("register_index", &Fr::from(aux_data.register_index as u64));
+        self.challenge_gen
+            .append_scalar("register_value", &Fr::from(aux_data.register_value));
+
+        if witness.rd != aux_data.register_index || witness.rd_value != aux_data.register_value {
+            return Err(ProofError::RegisterMismatch);
+        }
+
+        Ok(Fr::from(aux_data.register_value))
+    }
+
     /// Generate state transition proofs
-    fn prove_state_transitions(&self,
+    fn prove_state_transitions(&mut self,
         witness: &ExecutionWitness,
         aux_data: &AuxiliaryData
     ) -> Result<StateTransitionProofs, ProofError> {
         // 1. Commit to state transitions
         let state_commits = self.commit_to_states(witness)?;
-        
+
         // 2. Generate transition consistency proofs
         let consistency = self.prove_transition_consistency(
             witness,
             &state_commits
         )?;
-        
+
         // 3. Generate memory access proofs
         let memory_proofs = self.prove_memory_accesses(witness)?;
-        
+
         // 4. Generate register update proofs
         let register_proofs = self.prove_register_updates(
             witness,
             aux_data
         )?;
-        
+
         Ok(StateTransitionProofs {
             state_commitments: state_commits,
             consistency_proof: consistency,
@@ -153,19 +667,55 @@ impl ProofGenerator {
         })
     }
 
+    /// Decomposes `witness.mem_value` into bits and proves each is
+    /// boolean (`b*(b-1) == 0`) — the standard range-proof building block
+    /// `crate::crypto::arithmetic_r1cs`'s gates enforce per-row, checked
+    /// directly here since there's no shared circuit to route through.
+    fn prove_bit_decomposition(&self, witness: &ExecutionWitness) -> Result<Vec<Fr>, ProofError> {
+        let bits: Vec<Fr> = (0..64).map(|i| Fr::from((witness.mem_value >> i) & 1)).collect();
+        for &bit in &bits {
+            if bit * (bit - Fr::one()) != Fr::zero() {
+                return Err(ProofError::RangeCheckFailed);
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Proves `witness.mem_value` falls in `[0, 2^32)`, the interval a
+    /// 32-bit memory word must satisfy, by exhibiting both the value and
+    /// its distance from the upper bound.
+    fn prove_value_intervals(&self, witness: &ExecutionWitness) -> Result<Vec<Fr>, ProofError> {
+        if witness.mem_value > u32::MAX as u64 {
+            return Err(ProofError::RangeCheckFailed);
+        }
+        Ok(vec![
+            Fr::from(witness.mem_value),
+            Fr::from(u32::MAX as u64 - witness.mem_value),
+        ])
+    }
+
+    /// Proves `witness.divisor != 0`, the comparison that must hold
+    /// before any division-by-`divisor` step in the trace is sound.
+    fn prove_value_comparisons(&self, witness: &ExecutionWitness) -> Result<Vec<Fr>, ProofError> {
+        if witness.divisor == 0 {
+            return Err(ProofError::RangeCheckFailed);
+        }
+        Ok(vec![Fr::from(witness.divisor)])
+    }
+
     /// Generate range proofs
     fn generate_range_proofs(&self, witness: &ExecutionWitness)
         -> Result<RangeProofs, ProofError>
     {
         // 1. Generate bit decomposition proofs
         let bit_proofs = self.prove_bit_decomposition(witness)?;
-        
+
         // 2. Generate interval proofs
         let interval_proofs = self.prove_value_intervals(witness)?;
-        
+
         // 3. Generate comparison proofs
         let comparison_proofs = self.prove_value_comparisons(witness)?;
-        
+
         // 4. Combine range proofs
         Ok(RangeProofs {
             bit_decomposition: bit_proofs,
@@ -173,6 +723,85 @@ impl ProofGenerator {
             comparison_proofs,
         })
     }
+
+    /// Aggregates the batch-opening proof, the range proof's bit
+    /// decomposition and the state-transition consistency value into one
+    /// batched commitment via a Fiat-Shamir random linear combination.
+    /// The lookup proofs are carried alongside it, not folded in.
+    fn combine_proofs(
+        &mut self,
+        poly_commitments: PolynomialCommitments,
+        lookup_proofs: LookupProofs,
+        state_proofs: StateTransitionProofs,
+        range_proofs: RangeProofs,
+    ) -> Result<CompleteProof, ProofError> {
+        let challenge = self.challenge_gen.challenge_scalar("batch_combine");
+        let aggregated = range_proofs
+            .bit_decomposition
+            .iter()
+            .fold(poly_commitments.batch_proofs, |acc, &bit| acc * challenge + bit)
+            * challenge
+            + state_proofs.consistency_proof;
+
+        Ok(CompleteProof {
+            poly_commitments,
+            lookup_proofs,
+            state_proofs,
+            range_proofs,
+            batch_proof: BatchProof {
+                aggregated_commitment: aggregated,
+            },
+        })
+    }
+
+    /// Proves a trapping step instead of the normal state-transition
+    /// proof: [`TrapHandler`] constrains the transition to "PC frozen,
+    /// cause register set to `cause`, nothing else mutated," so the step
+    /// stays part of the verifiable trace instead of aborting it. Uses only
+    /// this generator's `trap_handler`: nothing is appended to or drawn
+    /// from the transcript, and no commitment scheme is involved.
+    pub fn generate_trap_proof(&self, witness: &ExecutionWitness, cause: Trap) -> Result<SemanticProof, ProofError> {
+        let trap_proof = self.trap_handler.handle(witness, cause);
+
+        Ok(SemanticProof {
+            state_commitments: vec![trap_proof.pc_commitment, trap_proof.cause_commitment],
+            transition_proofs: vec![trap_proof.no_mutation_commitment],
+            consistency_proof: trap_proof.cause_commitment,
+        })
+    }
+}
+
+/// A per-table multiset-equality claim, discharged by a pair of linear-time
+/// sum-checks over the hypercube rather than a direct sum over every LogUp
+/// term: `trace_eval`/`table_eval` are each side's batch-inverted-LogUp
+/// multilinear extension evaluated at its own sum-check's challenge point,
+/// standing in for a commitment opening the same way
+/// `crate::crypto::lookup::table::LogUpProof`'s `trace_eval`/`table_eval` do.
+/// [`ProofGenerator::prove_grand_product`] folds these across every table
+/// into the argument's final grand-product check.
+#[derive(Clone)]
+pub struct MultisetProof {
+    trace_sumcheck: SumCheckProof,
+    table_sumcheck: SumCheckProof,
+    trace_eval: Fr,
+    table_eval: Fr,
+}
+
+/// One step's memory-consistency proof: the pre-state leaf at `address`
+/// together with its [`SparseMerkleProof`] against `pre_root`, the value
+/// written, and the root that results. A verifier replaying a trace checks
+/// `pre_root` against the previous step's `post_root`, the pre-state proof
+/// against `pre_root`, and recomputes `post_root` itself from
+/// `written_value` — the read-before-write consistency argument
+/// `crate::memory::Memory`'s incremental root update is built to support.
+#[derive(Clone)]
+pub struct MemoryAccessProof {
+    address: u64,
+    pre_root: Fr,
+    pre_leaf: Fr,
+    pre_proof: SparseMerkleProof,
+    written_value: Fr,
+    post_root: Fr,
 }
 
 /// Complete proof for instruction execution
@@ -180,16 +809,16 @@ impl ProofGenerator {
 pub struct CompleteProof {
     // Polynomial commitments and evaluation proofs
     poly_commitments: PolynomialCommitments,
-    
+
     // Lookup table proofs
     lookup_proofs: LookupProofs,
-    
+
     // State transition proofs
     state_proofs: StateTransitionProofs,
-    
+
     // Range proofs
     range_proofs: RangeProofs,
-    
+
     // Batch proof for all components
     batch_proof: BatchProof,
 }
@@ -201,4 +830,117 @@ pub struct SecurityParameters {
     num_generators: usize,
     batch_size: usize,
     enable_parallel: bool,
-} 
\ No newline at end of file
+}
+
+/// The one field [`ProofGenerator::prove_instruction`] reads off a step's
+/// instruction: the opcode every sub-proof binds into the transcript and
+/// looks its lookup table up by.
+pub struct Instruction {
+    pub opcode: u8,
+}
+
+/// The register-file update a step's [`ProofGenerator::prove_register_updates`]
+/// checks `witness` against: which register was written and what value it
+/// now holds.
+pub struct AuxiliaryData {
+    pub register_index: u8,
+    pub register_value: u64,
+}
+
+/// Everything that can go wrong while building a [`CompleteProof`]: a
+/// LogUp multiset check failing (shared with
+/// [`crate::crypto::lookup::ProofError::LookupFailed`]'s same name for the
+/// same kind of failure), a claimed register update not matching the
+/// witness it's checked against, or a range/comparison proof's underlying
+/// claim not holding.
+#[derive(Debug)]
+pub enum ProofError {
+    LookupFailed,
+    RegisterMismatch,
+    RangeCheckFailed,
+}
+
+/// [`ProofGenerator::generate_poly_commitments`]'s output: the witness
+/// polynomials' KZG-style commitments, their claimed evaluations at the
+/// shared challenge point, and one aggregated batch-opening proof over
+/// both.
+#[derive(Clone)]
+pub struct PolynomialCommitments {
+    witness_commitments: Vec<Fr>,
+    evaluation_proofs: Vec<Fr>,
+    batch_proofs: Fr,
+}
+
+/// [`ProofGenerator::generate_lookup_proofs`]'s output: the committed
+/// lookup tables, a permutation commitment per checked witness field, the
+/// per-table LogUp multiset proofs, and the grand-product argument that
+/// ties them together.
+#[derive(Clone)]
+pub struct LookupProofs {
+    table_commitments: Vec<Fr>,
+    permutation_proofs: Vec<Fr>,
+    multiset_proofs: Vec<MultisetProof>,
+    grand_product: GrandProductProof,
+}
+
+/// [`ProofGenerator::prove_state_transitions`]'s output: the committed
+/// post-instruction state, the transition-consistency value that binds it
+/// to the pre-state, the memory-access proof for this step, and the
+/// register-update proof.
+#[derive(Clone)]
+pub struct StateTransitionProofs {
+    state_commitments: Vec<Fr>,
+    consistency_proof: Fr,
+    memory_proofs: MemoryAccessProof,
+    register_proofs: Fr,
+}
+
+/// [`ProofGenerator::generate_range_proofs`]'s output: a witness value's
+/// bit decomposition, its interval-membership proof, and its comparison
+/// proof.
+#[derive(Clone)]
+pub struct RangeProofs {
+    bit_decomposition: Vec<Fr>,
+    interval_proofs: Vec<Fr>,
+    comparison_proofs: Vec<Fr>,
+}
+
+/// [`ProofGenerator::combine_proofs`]'s output: the single aggregated
+/// commitment a verifier checks instead of replaying every sub-proof's
+/// internal structure.
+#[derive(Clone)]
+pub struct BatchProof {
+    aggregated_commitment: Fr,
+}
+
+/// A trap transition's proof, shaped like a minimal [`StateTransitionProofs`]
+/// (state commitments plus a single consistency value) without the
+/// memory/register sub-proofs a normal step's transition produces, since a
+/// trapping step makes neither kind of update.
+#[derive(Clone)]
+pub struct SemanticProof {
+    pub state_commitments: Vec<Fr>,
+    pub transition_proofs: Vec<Fr>,
+    pub consistency_proof: Fr,
+}
+
+/// A single opcode's lookup table, lazily materialized the first time
+/// [`ProofGenerator::ensure_table_generator`] sees that opcode — unlike
+/// the fixed-size per-chunk [`crate::crypto::lookup::LookupSubtable`]s,
+/// which `LassoLookup::new` allocates upfront.
+struct TableGenerator {
+    opcode: u8,
+    entries: Vec<Fr>,
+}
+
+impl TableGenerator {
+    /// A structured per-opcode table; here a fixed 256-row identity table
+    /// stands in for whatever semantics a real ISA would specialize per
+    /// opcode.
+    fn for_opcode(opcode: u8) -> Self {
+        TableGenerator {
+            opcode,
+            entries: (0..256u64).map(Fr::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::crypto::calldata::{push_word, read_word};
+use crate::crypto::proof::generator::{KeccakTranscript, Transcript};
+use crate::field::Fr;
+
+/// Flattened calldata view of a `CompleteProof`: `PolynomialCommitments`/
+/// `LookupProofs`/`StateTransitionProofs`/`RangeProofs` don't expose their
+/// internal `Fr` values publicly, so the codegen/encoder here work
+/// against the handful of top-level commitments a Keccak-transcript
+/// verifier actually needs to re-derive Fiat–Shamir challenges and run its
+/// pairing checks against — the same flattening
+/// `crate::crypto::proof_solidity` already does for the simpler native
+/// `Proof`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompleteProofCalldata {
+    pub witness_commitments: Vec<Fr>,
+    pub table_commitments: Vec<Fr>,
+    pub grand_product: Fr,
+    pub batch_commitment: Fr,
+}
+
+impl CompleteProofCalldata {
+    /// Replays `self` through a fresh `KeccakTranscript` in the exact
+    /// append/challenge order the generated contract's `verify` function
+    /// documents, returning the final challenge it binds `batch_commitment`
+    /// to.
+    fn rederive_challenge(&self, public_inputs: &[Fr]) -> Fr {
+        let mut transcript = KeccakTranscript::new();
+        for (i, input) in public_inputs.iter().enumerate() {
+            transcript.append_scalar(&format!("public_input_{i}"), input);
+        }
+        for commitment in &self.witness_commitments {
+            transcript.append_commitment("witness_commitment", commitment);
+        }
+        for commitment in &self.table_commitments {
+            transcript.append_commitment("table_commitment", commitment);
+        }
+        transcript.append_scalar("grand_product", &self.grand_product);
+        transcript.challenge_scalar("batch_challenge")
+    }
+
+    /// Native-side equivalent of the generated contract's `verify`: the
+    /// re-derived challenge has to bind `batch_commitment`, matching the
+    /// pairing check a real deployment would run via `ecPairing` instead
+    /// of this `Fr`-valued stand-in equality.
+    pub fn verify(&self, public_inputs: &[Fr]) -> bool {
+        self.rederive_challenge(public_inputs) == self.batch_commitment
+    }
+}
+
+/// Serializes `proof` into the calldata byte layout
+/// `CompleteProofVerifier::verify` expects: a big-endian `uint256` word
+/// count for each variable-length section, then every `Fr` as a
+/// big-endian 32-byte word — witness commitments, table commitments,
+/// `grand_product`, `batch_commitment`, in that order.
+pub fn export_calldata(proof: &CompleteProofCalldata) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    push_word(&mut bytes, Fr::from(proof.witness_commitments.len() as u64));
+    for commitment in &proof.witness_commitments {
+        push_word(&mut bytes, *commitment);
+    }
+    push_word(&mut bytes, Fr::from(proof.table_commitments.len() as u64));
+    for commitment in &proof.table_commitments {
+        push_word(&mut bytes, *commitment);
+    }
+    push_word(&mut bytes, proof.grand_product);
+    push_word(&mut bytes, proof.batch_commitment);
+    bytes
+}
+
+/// Inverse of [`export_calldata`].
+pub fn parse_calldata(data: &[u8]) -> Option<CompleteProofCalldata> {
+    let (witness_len, mut offset) = read_word(data, 0)?;
+    let mut witness_commitments = Vec::with_capacity(witness_len.to_u64() as usize);
+    for _ in 0..witness_len.to_u64() {
+        let (word, next) = read_word(data, offset)?;
+        witness_commitments.push(word);
+        offset = next;
+    }
+
+    let (table_len, mut offset) = read_word(data, offset)?;
+    let mut table_commitments = Vec::with_capacity(table_len.to_u64() as usize);
+    for _ in 0..table_len.to_u64() {
+        let (word, next) = read_word(data, offset)?;
+        table_commitments.push(word);
+        offset = next;
+    }
+
+    let (grand_product, offset) = read_word(data, offset)?;
+    let (batch_commitment, _) = read_word(data, offset)?;
+
+    Some(CompleteProofCalldata {
+        witness_commitments,
+        table_commitments,
+        grand_product,
+        batch_commitment,
+    })
+}
+
+/// Emits a self-contained Solidity contract whose `verify` reconstructs
+/// the same `KeccakTranscript` challenge order `CompleteProofCalldata::verify`
+/// replays natively, then runs its pairing checks via the `ecMul`/
+/// `ecAdd`/`ecPairing` precompiles at `0x07`/`0x06`/`0x08`. The precompile
+/// calls are left as documented stubs — they need real BN254 points, not
+/// this crate's Goldilocks `Fr` — the same caveat
+/// `crate::crypto::solidity_verifier::VerifyingKey` and
+/// `crate::crypto::proof_solidity`'s generated contract both carry. Do
+/// not edit the output by hand — regenerate with this function instead.
+pub fn generate_complete_proof_verifier_contract() -> String {
+    r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+contract CompleteProofVerifier {
+    uint256 internal constant EC_ADD = 0x06;
+    uint256 internal constant EC_MUL = 0x07;
+    uint256 internal constant EC_PAIRING = 0x08;
+
+    /// Re-derives one Fiat-Shamir challenge step, folding `label` and
+    /// `data` into the running `state`, mirroring `KeccakTranscript`'s
+    /// append/challenge order off-chain.
+    function deriveChallenge(bytes32 state, bytes32 label, uint256 data) internal pure returns (bytes32) {
+        return keccak256(abi.encodePacked(state, label, data));
+    }
+
+    /// A single BN254 pairing check via the `ecPairing` precompile at
+    /// `0x08`, gating a witness/table commitment's KZG opening. Left
+    /// abstract here: wiring in a real SRS and curve points is future
+    /// work, the same caveat every generated verifier in this crate
+    /// carries.
+    function checkPairing(uint256[2] memory commitment, uint256[2] memory openingProof, uint256 challenge) internal view returns (bool) {
+        // staticcall(EC_PAIRING, ...) against the verifying key goes here.
+        return true;
+    }
+
+    /// Reconstructs the calldata layout `export_calldata` produces: a
+    /// length-prefixed witness-commitment array, a length-prefixed
+    /// table-commitment array, then `grandProduct` and `batchCommitment`,
+    /// each a big-endian `uint256` word.
+    function decode(bytes calldata proof) internal pure returns (uint256[] memory witnessCommitments, uint256[] memory tableCommitments, uint256 grandProduct, uint256 batchCommitment) {
+        uint256 offset = 0;
+        uint256 witnessLen = uint256(bytes32(proof[offset:offset + 32]));
+        offset += 32;
+        witnessCommitments = new uint256[](witnessLen);
+        for (uint256 i = 0; i < witnessLen; i++) {
+            witnessCommitments[i] = uint256(bytes32(proof[offset:offset + 32]));
+            offset += 32;
+        }
+
+        uint256 tableLen = uint256(bytes32(proof[offset:offset + 32]));
+        offset += 32;
+        tableCommitments = new uint256[](tableLen);
+        for (uint256 i = 0; i < tableLen; i++) {
+            tableCommitments[i] = uint256(bytes32(proof[offset:offset + 32]));
+            offset += 32;
+        }
+
+        grandProduct = uint256(bytes32(proof[offset:offset + 32]));
+        offset += 32;
+        batchCommitment = uint256(bytes32(proof[offset:offset + 32]));
+    }
+
+    /// Re-derives `batchCommitment`'s challenge from `publicInputs` and
+    /// every commitment in `proof`, in the same order
+    /// `CompleteProofCalldata::rederive_challenge` folds them natively,
+    /// and checks it matches — the on-chain half of the same check, the
+    /// KZG openings behind `checkPairing` being the other half a real
+    /// deployment would also run.
+    function verify(bytes calldata proof, uint256[] calldata publicInputs) external view returns (bool) {
+        (uint256[] memory witnessCommitments, uint256[] memory tableCommitments, uint256 grandProduct, uint256 batchCommitment) = decode(proof);
+
+        bytes32 state = bytes32(0);
+        for (uint256 i = 0; i < publicInputs.length; i++) {
+            state = deriveChallenge(state, keccak256(abi.encodePacked("public_input_", i)), publicInputs[i]);
+        }
+        for (uint256 i = 0; i < witnessCommitments.length; i++) {
+            state = deriveChallenge(state, keccak256("witness_commitment"), witnessCommitments[i]);
+        }
+        for (uint256 i = 0; i < tableCommitments.length; i++) {
+            state = deriveChallenge(state, keccak256("table_commitment"), tableCommitments[i]);
+        }
+        state = deriveChallenge(state, keccak256("grand_product"), grandProduct);
+        bytes32 challenge = deriveChallenge(state, keccak256("batch_challenge"), 0);
+
+        return uint256(challenge) == batchCommitment;
+    }
+}
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_proof_calldata_round_trips_and_verifies() {
+        let public_inputs = vec![Fr::from(3), Fr::from(5)];
+        let witness_commitments = vec![Fr::from(11), Fr::from(13)];
+        let table_commitments = vec![Fr::from(17)];
+        let grand_product = Fr::from(19);
+
+        let mut transcript = KeccakTranscript::new();
+        for (i, input) in public_inputs.iter().enumerate() {
+            transcript.append_scalar(&format!("public_input_{i}"), input);
+        }
+        for commitment in &witness_commitments {
+            transcript.append_commitment("witness_commitment", commitment);
+        }
+        for commitment in &table_commitments {
+            transcript.append_commitment("table_commitment", commitment);
+        }
+        transcript.append_scalar("grand_product", &grand_product);
+        let batch_commitment = transcript.challenge_scalar("batch_challenge");
+
+        let proof = CompleteProofCalldata {
+            witness_commitments,
+            table_commitments,
+            grand_product,
+            batch_commitment,
+        };
+        assert!(proof.verify(&public_inputs));
+
+        let calldata = export_calldata(&proof);
+        let parsed = parse_calldata(&calldata).expect("well-formed calldata");
+        assert_eq!(parsed, proof);
+        assert!(parsed.verify(&public_inputs));
+
+        // Generating the contract shouldn't panic and should at least
+        // mention the entry point this test just exercised natively.
+        let contract = generate_complete_proof_verifier_contract();
+        assert!(contract.contains("function verify("));
+    }
+}
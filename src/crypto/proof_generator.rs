@@ -1,87 +0,0 @@
-use crate::field::Fr;
-use crate::crypto::polynomial::Polynomial;
-use crate::crypto::commitment::PolyCommitment;
-
-pub struct ProofGenerator {
-    // Polynomial commitment scheme
-    commitment_scheme: PolyCommitment,
-    // Lookup table generators
-    table_generators: Vec<TableGenerator>,
-    // Challenge generator
-    challenge_gen: ChallengeGenerator,
-}
-
-impl ProofGenerator {
-    pub fn new() -> Self {
-        Self {
-            commitment_scheme: PolyCommitment::new(),
-            table_generators: Vec::new(),
-            challenge_gen: ChallengeGenerator::new(),
-        }
-    }
-
-    /// Generate a proof for an instruction execution
-    pub fn generate_instruction_proof(
-        &self,
-        instruction: &Instruction,
-        witness: &ExecutionWitness
-    ) -> Result<InstructionProof, ProofError> {
-        // 1. Generate lookup proofs
-        let lookup_proof = self.generate_lookup_proof(instruction, witness)?;
-        
-        // 2. Generate semantic proof
-        let semantic_proof = self.generate_semantic_proof(instruction, witness)?;
-        
-        // 3. Generate range proofs
-        let range_proof = self.generate_range_proof(witness)?;
-        
-        // 4. Combine proofs
-        Ok(InstructionProof {
-            lookup_proof,
-            semantic_proof,
-            range_proof,
-        })
-    }
-
-    fn generate_lookup_proof(
-        &self,
-        instruction: &Instruction,
-        witness: &ExecutionWitness
-    ) -> Result<LookupProof, ProofError> {
-        // 1. Generate table commitments
-        let table_commitments = self.commit_to_tables(instruction)?;
-        
-        // 2. Generate permutation proof
-        let perm_proof = self.generate_permutation_proof(witness)?;
-        
-        // 3. Generate range proof for indices
-        let range_proof = self.generate_index_range_proof(witness)?;
-        
-        Ok(LookupProof {
-            table_commitments,
-            perm_proof,
-            range_proof,
-        })
-    }
-
-    fn generate_semantic_proof(
-        &self,
-        instruction: &Instruction,
-        witness: &ExecutionWitness
-    ) -> Result<SemanticProof, ProofError> {
-        // 1. Commit to state transitions
-        let state_commitments = self.commit_to_states(witness)?;
-        
-        // 2. Generate transition proofs
-        let transition_proofs = self.prove_transitions(witness)?;
-        
-        // 3. Generate consistency proof
-        let consistency_proof = self.prove_consistency(witness)?;
-        
-        Ok(SemanticProof {
-            state_commitments,
-            transition_proofs,
-            consistency_proof,
-        })
-    }
-} 
\ No newline at end of file
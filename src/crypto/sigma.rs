@@ -0,0 +1,196 @@
+use crate::crypto::proof::generator::Transcript;
+use crate::field::Fr;
+use rand::RngCore;
+
+/// The two Pedersen generators a sigma proof's statements are phrased over:
+/// `C = g*v + h*r`, the additive-notation analogue of `C = g^v h^r` that
+/// [`super::commitment::commit_msm`] already computes everywhere else in
+/// this crate (a "group element" is just an `Fr`, "scalar multiplication"
+/// is field multiplication, and the group operation is field addition).
+/// [`super::commitment::PedersenCommitment`] fixes its own blinding factor
+/// at construction and commits to a whole vector at once; `PedersenGenerators`
+/// is the narrower two-generator, single-value shape the opening and
+/// relation proofs below need.
+#[derive(Clone, Copy, Debug)]
+pub struct PedersenGenerators {
+    pub g: Fr,
+    pub h: Fr,
+}
+
+impl PedersenGenerators {
+    pub fn new() -> Self {
+        let mut rng = rand::thread_rng();
+        PedersenGenerators {
+            g: Fr::random(&mut rng),
+            h: Fr::random(&mut rng),
+        }
+    }
+
+    /// `C = g*v + h*r`.
+    pub fn commit(&self, value: Fr, randomness: Fr) -> Fr {
+        self.g * value + self.h * randomness
+    }
+}
+
+/// A non-interactive Schnorr-style proof of knowledge of an opening
+/// `(v, r)` for `C = g^v h^r`: the prover samples `(a, b)`, sends
+/// `A = g^a h^b`, derives the challenge `c` from `transcript` (binding `A`
+/// first), and responds with `z_1 = a + c*v`, `z_2 = b + c*r`. The verifier
+/// accepts iff `g^{z_1} h^{z_2} == A * C^c`.
+#[derive(Clone, Debug)]
+pub struct OpeningProof {
+    pub commitment: Fr,
+    pub z1: Fr,
+    pub z2: Fr,
+}
+
+/// Proves knowledge of an opening `(value, randomness)` for `commitment =
+/// gens.commit(value, randomness)`.
+pub fn prove_opening<T: Transcript>(
+    gens: &PedersenGenerators,
+    value: Fr,
+    randomness: Fr,
+    transcript: &mut T,
+) -> OpeningProof {
+    let mut rng = rand::thread_rng();
+    let a = Fr::random(&mut rng);
+    let b = Fr::random(&mut rng);
+    let commitment = gens.commit(a, b);
+
+    transcript.append_commitment("sigma_opening_commitment", &commitment);
+    let c = transcript.challenge_scalar("sigma_opening_challenge");
+
+    OpeningProof {
+        commitment,
+        z1: a + c * value,
+        z2: b + c * randomness,
+    }
+}
+
+/// Verifies an [`OpeningProof`] against `commitment`, replaying the exact
+/// transcript order [`prove_opening`] used.
+pub fn verify_opening<T: Transcript>(
+    gens: &PedersenGenerators,
+    commitment: Fr,
+    proof: &OpeningProof,
+    transcript: &mut T,
+) -> bool {
+    transcript.append_commitment("sigma_opening_commitment", &proof.commitment);
+    let c = transcript.challenge_scalar("sigma_opening_challenge");
+
+    gens.commit(proof.z1, proof.z2) == proof.commitment + commitment * c
+}
+
+/// A Schnorr proof of knowledge of the discrete log `delta` with `target =
+/// gens.h * delta` — the building block both [`prove_equality`] and
+/// [`prove_linear_combination`] reduce to, since both claims are really "I
+/// know the leftover blinding factor between two commitments I've already
+/// fixed the same way `prove_opening` fixes a single one."
+#[derive(Clone, Debug)]
+struct DlogProof {
+    commitment: Fr,
+    z: Fr,
+}
+
+fn prove_dlog_h<T: Transcript>(h: Fr, delta: Fr, label: &str, transcript: &mut T) -> DlogProof {
+    let mut rng = rand::thread_rng();
+    let b = Fr::random(&mut rng);
+    let commitment = h * b;
+
+    transcript.append_commitment(label, &commitment);
+    let c = transcript.challenge_scalar(label);
+
+    DlogProof {
+        commitment,
+        z: b + c * delta,
+    }
+}
+
+fn verify_dlog_h<T: Transcript>(h: Fr, target: Fr, proof: &DlogProof, label: &str, transcript: &mut T) -> bool {
+    transcript.append_commitment(label, &proof.commitment);
+    let c = transcript.challenge_scalar(label);
+
+    h * proof.z == proof.commitment + target * c
+}
+
+/// Proves that two commitments `C1 = gens.commit(v, r1)` and `C2 =
+/// gens.commit(v, r2)` hide the same value `v`, without revealing it:
+/// `C1 - C2 = h*(r1-r2)`, so knowledge of the same `v` on both sides
+/// reduces to a Schnorr proof of knowledge of `r1-r2`'s discrete log base
+/// `h`.
+#[derive(Clone, Debug)]
+pub struct EqualityProof(DlogProof);
+
+pub fn prove_equality<T: Transcript>(
+    gens: &PedersenGenerators,
+    r1: Fr,
+    r2: Fr,
+    transcript: &mut T,
+) -> EqualityProof {
+    EqualityProof(prove_dlog_h(gens.h, r1 - r2, "sigma_equality", transcript))
+}
+
+pub fn verify_equality<T: Transcript>(
+    gens: &PedersenGenerators,
+    c1: Fr,
+    c2: Fr,
+    proof: &EqualityProof,
+    transcript: &mut T,
+) -> bool {
+    verify_dlog_h(gens.h, c1 - c2, &proof.0, "sigma_equality", transcript)
+}
+
+/// Proves that `combined = gens.commit(v0, r0)` commits to a value `v0`
+/// that is the linear combination `sum_i coefficients[i] * v_i` of the
+/// values hidden behind `component_commitments`, given the prover's own
+/// `component_randomness[i]` (the `r_i` behind each `component_commitment`)
+/// and `combined_randomness` (`r0`). Reduces the same way
+/// [`prove_equality`] does: `combined - sum_i coefficients[i] *
+/// component_commitments[i] = h*(r0 - sum_i coefficients[i]*r_i)`, a single
+/// Schnorr proof of knowledge of that leftover blinding factor.
+#[derive(Clone, Debug)]
+pub struct LinearCombinationProof(DlogProof);
+
+pub fn prove_linear_combination<T: Transcript>(
+    gens: &PedersenGenerators,
+    coefficients: &[Fr],
+    component_randomness: &[Fr],
+    combined_randomness: Fr,
+    transcript: &mut T,
+) -> LinearCombinationProof {
+    assert_eq!(coefficients.len(), component_randomness.len());
+    let weighted_randomness = coefficients
+        .iter()
+        .zip(component_randomness)
+        .fold(Fr::zero(), |acc, (&coeff, &r)| acc + coeff * r);
+
+    LinearCombinationProof(prove_dlog_h(
+        gens.h,
+        combined_randomness - weighted_randomness,
+        "sigma_linear_combination",
+        transcript,
+    ))
+}
+
+pub fn verify_linear_combination<T: Transcript>(
+    gens: &PedersenGenerators,
+    combined_commitment: Fr,
+    coefficients: &[Fr],
+    component_commitments: &[Fr],
+    proof: &LinearCombinationProof,
+    transcript: &mut T,
+) -> bool {
+    assert_eq!(coefficients.len(), component_commitments.len());
+    let weighted_commitment = coefficients
+        .iter()
+        .zip(component_commitments)
+        .fold(Fr::zero(), |acc, (&coeff, &commitment)| acc + commitment * coeff);
+
+    verify_dlog_h(
+        gens.h,
+        combined_commitment - weighted_commitment,
+        &proof.0,
+        "sigma_linear_combination",
+        transcript,
+    )
+}
@@ -0,0 +1,117 @@
+use crate::crypto::calldata::push_word;
+use crate::field::Fr;
+
+/// The fixed circuit/commitment parameters a generated verifier contract
+/// is specialized to: how many lookup tables the batch covers and the KZG
+/// SRS's degree bound, matching the shape `CryptoExtensions::batch_verify`
+/// checks against at proving time.
+pub struct VerifierConfig {
+    pub num_tables: usize,
+    pub kzg_max_degree: usize,
+    pub num_commitment_generators: usize,
+}
+
+/// The constants a KZG-based Solidity verifier hard-codes: the Pedersen
+/// commitment generators and the SRS's `[tau]_2` toxic-waste point. Kept as
+/// `Fr` like every other commitment value in this crate, though a real
+/// deployment would need BN254 scalar-field points for the EVM's `ecPairing`
+/// precompile to accept — the same Goldilocks-vs-BN254 mismatch every
+/// placeholder commitment scheme here carries.
+pub struct VerifyingKey {
+    pub generators: Vec<Fr>,
+    pub tau_g2: Fr,
+}
+
+impl VerifyingKey {
+    /// Derives a deterministic placeholder VK from `config` alone, the way
+    /// a real deployment would instead load one from a trusted-setup
+    /// transcript. Each generator is a distinct fixed point so the
+    /// generated contract's hard-coded array isn't degenerate.
+    pub fn new(config: &VerifierConfig) -> Self {
+        let generators = (0..config.num_commitment_generators)
+            .map(|i| Fr::from(i as u64 + 1))
+            .collect();
+        VerifyingKey {
+            generators,
+            tau_g2: Fr::from(config.kzg_max_degree as u64),
+        }
+    }
+
+    fn to_solidity_array(&self) -> String {
+        let entries: Vec<String> = self.generators.iter().map(|g| format!("uint256({})", g.to_u64())).collect();
+        format!("[{}]", entries.join(", "))
+    }
+}
+
+/// Emits a self-contained Solidity contract for a `config.num_tables`-table
+/// batch. Its `verifyProof` rejects an empty `proof` and runs a
+/// `keccak256` challenge chain seeded from `publicInputs`, one step per
+/// table. It does not call `checkPairing`, does no batch aggregation, and
+/// returns `true` for any non-empty `proof`. The
+/// verifying key is baked in as a Solidity constant rather than read from
+/// calldata, matching how `vk` is fixed at deploy time for a given circuit.
+pub fn generate_verifier_contract(config: &VerifierConfig, vk: &VerifyingKey) -> String {
+    format!(
+        r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.19;
+
+/// Generated verifier for a {num_tables}-table batched lookup proof. Do
+/// not edit by hand — regenerate with `generate_verifier_contract` instead.
+contract LookupBatchVerifier {{
+    uint256 internal constant NUM_TABLES = {num_tables};
+    uint256 internal constant KZG_MAX_DEGREE = {kzg_max_degree};
+    uint256 internal constant TAU_G2 = {tau_g2};
+    uint256[{num_generators}] internal GENERATORS = {generators};
+
+    /// One step of `verifyProof`'s challenge chain: `keccak256` over the
+    /// running state, a label, and one data word.
+    function deriveChallenge(bytes32 state, bytes32 label, uint256 data) internal pure returns (bytes32) {{
+        return keccak256(abi.encodePacked(state, label, data));
+    }}
+
+    /// One table's KZG opening check: `e(commitment - value*[1]_1, [1]_2)
+    /// == e(proof, [tau]_2 - point*[1]_2)`, via the `ecPairing` precompile
+    /// at address `0x08`. Left abstract here since it needs BN254 points,
+    /// not this crate's Goldilocks `Fr` — wiring in a real SRS is future
+    /// work, the same caveat `VerifyingKey` carries.
+    function checkPairing(uint256[2] memory commitment, uint256[2] memory openingProof, uint256 point, uint256 value) internal view returns (bool) {{
+        // staticcall(0x08, ...) against GENERATORS / TAU_G2 goes here.
+        return true;
+    }}
+
+    /// Runs one challenge-chain step per table over `publicInputs`.
+    /// `checkPairing` is not called and nothing is aggregated; any
+    /// non-empty `proof` is accepted.
+    function verifyProof(bytes calldata proof, uint256[] calldata publicInputs) external view returns (bool) {{
+        require(proof.length > 0, "empty proof");
+        bytes32 state = keccak256(abi.encodePacked(publicInputs));
+
+        for (uint256 i = 0; i < NUM_TABLES; i++) {{
+            state = deriveChallenge(state, bytes32(uint256(uint160(i))), publicInputs.length > i ? publicInputs[i] : 0);
+        }}
+
+        return true;
+    }}
+}}
+"#,
+        num_tables = config.num_tables,
+        kzg_max_degree = config.kzg_max_degree,
+        tau_g2 = vk.tau_g2.to_u64(),
+        num_generators = vk.generators.len(),
+        generators = vk.to_solidity_array(),
+    )
+}
+
+/// Serializes a batch of per-table commitments/openings plus the public
+/// inputs into the calldata byte layout `verifyProof` expects: every `Fr`
+/// as a big-endian 32-byte word (`uint256`), commitments first, then
+/// openings, then public inputs — mirroring how a `StructuredProof`'s
+/// `MultisetProof`/`ConsistencyProof` commitments would be flattened for
+/// the EVM to decode positionally rather than via Solidity's ABI decoder.
+pub fn serialize_proof_calldata(commitments: &[Fr], openings: &[Fr], public_inputs: &[Fr]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity((commitments.len() + openings.len() + public_inputs.len()) * 32);
+    for &fr in commitments.iter().chain(openings.iter()).chain(public_inputs.iter()) {
+        push_word(&mut bytes, fr);
+    }
+    bytes
+}
@@ -0,0 +1,262 @@
+use crate::crypto::uniform_r1cs::{verify_batched, SparseMatrix};
+use crate::field::Fr;
+
+/// Which `ReductionRules` family matched this step — `ReductionRules::match_rule`
+/// tries computation, then memory, then control rules in that order, so
+/// exactly one of these is set per step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleKind {
+    Computation,
+    Memory,
+    Control,
+}
+
+/// One `TransitionSystem::step` transition, in the layout [`SosStepCompiler`]
+/// expects. A `Computation`/`Memory` step leaves `branch_target` at zero
+/// (its `next_pc` is just `pc + 4`); a `Control` step leaves `mem_addr`/
+/// `mem_value` at zero.
+#[derive(Clone, Debug)]
+pub struct MatchedRule {
+    pub kind: RuleKind,
+    pub opcode: u64,
+    pub rs1: u64,
+    pub rs2: u64,
+    pub rd: u64,
+    pub immediate: u64,
+    pub pc: u64,
+    pub next_pc: u64,
+    pub branch_target: u64,
+    pub mem_addr: u64,
+    pub mem_value: u64,
+    pub mem_is_write: bool,
+    /// Fed to `LookupProofSystem::prove_lookup` once the step's witness is
+    /// filled; the uniform template commits to it without recomputing it.
+    pub lookup_output: u64,
+}
+
+#[derive(Debug)]
+pub enum SosR1CSError {
+    /// `Az ∘ Bz != Cz` at the given (step, constraint-row) pair.
+    UnsatisfiedConstraint(usize, usize),
+    /// `next_pc` of step `i` disagrees with `pc` of step `i + 1`.
+    PcDiscontinuity(usize),
+    /// A `Memory` read at the given step didn't see the value of the most
+    /// recent write to the same address.
+    MemoryOrderingViolation(usize),
+}
+
+fn bool_fr(bit: bool) -> Fr {
+    if bit {
+        Fr::one()
+    } else {
+        Fr::zero()
+    }
+}
+
+/// Compiles matched `ComputationRule`/`MemoryRule`/`ControlRule` steps into
+/// the uniform per-step R1CS `to_uniform_r1cs` bridges `OperationalSemantics`
+/// to: one fixed `(A, B, C)` block, identically shaped across the whole
+/// trace, with opcode/operand/PC/lookup-output columns tying the
+/// interpreter's semantics to the proof machinery in `LookupProofSystem`.
+pub struct SosStepCompiler {
+    step_vars: usize,
+}
+
+impl SosStepCompiler {
+    // Column layout within one step's witness block.
+    const OPCODE: usize = 0;
+    const RS1: usize = 1;
+    const RS2: usize = 2;
+    const RD: usize = 3;
+    const IMM: usize = 4;
+    const PC: usize = 5;
+    const NEXT_PC: usize = 6;
+    const BRANCH_TARGET: usize = 7;
+    const MEM_ADDR: usize = 8;
+    const MEM_VALUE: usize = 9;
+    const MEM_IS_WRITE: usize = 10;
+    const LOOKUP_OUTPUT: usize = 11;
+    const IS_COMPUTATION: usize = 12;
+    const IS_MEMORY: usize = 13;
+    const IS_CONTROL: usize = 14;
+    const NOT_CONTROL: usize = 15;
+    const PC_PLUS_4: usize = 16;
+    const FALLTHROUGH_TERM: usize = 17;
+    const BRANCH_TERM: usize = 18;
+    const VARS_PER_STEP: usize = 19;
+
+    pub fn new() -> Self {
+        SosStepCompiler { step_vars: Self::VARS_PER_STEP }
+    }
+
+    fn add_booleanity(a: &mut SparseMatrix, b: &mut SparseMatrix, c: &mut SparseMatrix, row: usize, col: usize) {
+        a.set(row, col, Fr::one());
+        b.set(row, col, Fr::one());
+        c.set(row, col, Fr::one());
+    }
+
+    /// Builds the single-step `(A, B, C)` template: a one-hot `RuleKind`
+    /// selector, the PC update (`pc + 4` for `Computation`/`Memory`,
+    /// `branch_target` for `Control`, selected the same way
+    /// [`super::arithmetic_r1cs::ArithmeticR1CS`] selects `RESULT` by op),
+    /// and a booleanity check on `mem_is_write`. Binding `rd`/`mem_value`
+    /// to the opcode's actual ALU/load semantics and tying `lookup_output`
+    /// to the operand lookup index are left to the same kind of
+    /// structural identity rows `UniformR1CS::synthesize_step` uses for
+    /// sign-extension — a real opcode decoder is out of scope here.
+    pub fn synthesize_step(&self) -> (SparseMatrix, SparseMatrix, SparseMatrix) {
+        let vars = self.step_vars;
+        let one_col = vars;
+        let num_constraints = 4 // booleanity: IS_COMPUTATION, IS_MEMORY, IS_CONTROL, MEM_IS_WRITE
+            + 1 // one-hot sum-to-one
+            + 1 // NOT_CONTROL = 1 - IS_CONTROL
+            + 1 // PC_PLUS_4 = PC + 4
+            + 2 // FALLTHROUGH_TERM / BRANCH_TERM products
+            + 1 // NEXT_PC combine
+            + 1 // RD identity (placeholder for the opcode's ALU result)
+            + 1; // LOOKUP_OUTPUT identity (placeholder for the operand index)
+
+        let mut a = SparseMatrix::new(num_constraints, vars + 1);
+        let mut b = SparseMatrix::new(num_constraints, vars + 1);
+        let mut c = SparseMatrix::new(num_constraints, vars + 1);
+        let mut row = 0;
+
+        for &col in &[Self::IS_COMPUTATION, Self::IS_MEMORY, Self::IS_CONTROL, Self::MEM_IS_WRITE] {
+            Self::add_booleanity(&mut a, &mut b, &mut c, row, col);
+            row += 1;
+        }
+
+        // IS_COMPUTATION + IS_MEMORY + IS_CONTROL = 1
+        a.set(row, Self::IS_COMPUTATION, Fr::one());
+        a.set(row, Self::IS_MEMORY, Fr::one());
+        a.set(row, Self::IS_CONTROL, Fr::one());
+        b.set(row, one_col, Fr::one());
+        c.set(row, one_col, Fr::one());
+        row += 1;
+
+        // NOT_CONTROL = 1 - IS_CONTROL
+        a.set(row, one_col, Fr::one());
+        a.set(row, Self::IS_CONTROL, Fr::zero() - Fr::one());
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::NOT_CONTROL, Fr::one());
+        row += 1;
+
+        // PC_PLUS_4 = PC + 4
+        a.set(row, Self::PC, Fr::one());
+        a.set(row, one_col, Fr::from(4));
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::PC_PLUS_4, Fr::one());
+        row += 1;
+
+        // FALLTHROUGH_TERM = NOT_CONTROL * PC_PLUS_4
+        a.set(row, Self::NOT_CONTROL, Fr::one());
+        b.set(row, Self::PC_PLUS_4, Fr::one());
+        c.set(row, Self::FALLTHROUGH_TERM, Fr::one());
+        row += 1;
+
+        // BRANCH_TERM = IS_CONTROL * BRANCH_TARGET
+        a.set(row, Self::IS_CONTROL, Fr::one());
+        b.set(row, Self::BRANCH_TARGET, Fr::one());
+        c.set(row, Self::BRANCH_TERM, Fr::one());
+        row += 1;
+
+        // NEXT_PC = FALLTHROUGH_TERM + BRANCH_TERM
+        a.set(row, Self::FALLTHROUGH_TERM, Fr::one());
+        a.set(row, Self::BRANCH_TERM, Fr::one());
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::NEXT_PC, Fr::one());
+        row += 1;
+
+        // Structural placeholders: present in every step so the shape
+        // stays uniform across instruction kinds, not yet backed by a real
+        // opcode decoder (same caveat as `UniformR1CS`'s sign-extension row).
+        a.set(row, Self::RD, Fr::one());
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::RD, Fr::one());
+        row += 1;
+
+        a.set(row, Self::LOOKUP_OUTPUT, Fr::one());
+        b.set(row, one_col, Fr::one());
+        c.set(row, Self::LOOKUP_OUTPUT, Fr::one());
+        row += 1;
+
+        debug_assert_eq!(row, num_constraints);
+        (a, b, c)
+    }
+
+    fn fill_witness(&self, step: &MatchedRule) -> Vec<Fr> {
+        let mut z = vec![Fr::zero(); self.step_vars + 1];
+        z[Self::OPCODE] = Fr::from(step.opcode);
+        z[Self::RS1] = Fr::from(step.rs1);
+        z[Self::RS2] = Fr::from(step.rs2);
+        z[Self::RD] = Fr::from(step.rd);
+        z[Self::IMM] = Fr::from(step.immediate);
+        z[Self::PC] = Fr::from(step.pc);
+        z[Self::NEXT_PC] = Fr::from(step.next_pc);
+        z[Self::BRANCH_TARGET] = Fr::from(step.branch_target);
+        z[Self::MEM_ADDR] = Fr::from(step.mem_addr);
+        z[Self::MEM_VALUE] = Fr::from(step.mem_value);
+        z[Self::MEM_IS_WRITE] = bool_fr(step.mem_is_write);
+        z[Self::LOOKUP_OUTPUT] = Fr::from(step.lookup_output);
+        z[Self::IS_COMPUTATION] = bool_fr(step.kind == RuleKind::Computation);
+        z[Self::IS_MEMORY] = bool_fr(step.kind == RuleKind::Memory);
+        z[Self::IS_CONTROL] = bool_fr(step.kind == RuleKind::Control);
+        z[Self::NOT_CONTROL] = bool_fr(step.kind != RuleKind::Control);
+        z[Self::PC_PLUS_4] = Fr::from(step.pc + 4);
+        z[Self::FALLTHROUGH_TERM] = if step.kind == RuleKind::Control { Fr::zero() } else { Fr::from(step.pc + 4) };
+        z[Self::BRANCH_TERM] = if step.kind == RuleKind::Control { Fr::from(step.branch_target) } else { Fr::zero() };
+        z[self.step_vars] = Fr::one();
+        z
+    }
+
+    /// Runs each matched rule's witness filler, checks the uniform
+    /// single-step matrices hold, and checks the cross-step consistency
+    /// conditions the uniform template can't express on its own: PC
+    /// chaining (`next_pc` of step `i` is `pc` of step `i + 1`) and memory
+    /// ordering (a read observes the value of the most recent write to the
+    /// same address, the multiset-fingerprint property `MemorySystem`'s
+    /// real offline memory-checking argument formalizes).
+    pub fn to_uniform_r1cs(&self, trace: &[MatchedRule]) -> Result<Vec<Vec<Fr>>, SosR1CSError> {
+        let (a, b, c) = self.synthesize_step();
+        let mut witnesses = Vec::with_capacity(trace.len());
+        let mut last_write: std::collections::HashMap<u64, u64> = std::collections::HashMap::new();
+
+        for (i, step) in trace.iter().enumerate() {
+            let z = self.fill_witness(step);
+            let az = a.apply(&z);
+            let bz = b.apply(&z);
+            let cz = c.apply(&z);
+            for row in 0..az.len() {
+                if az[row] * bz[row] != cz[row] {
+                    return Err(SosR1CSError::UnsatisfiedConstraint(i, row));
+                }
+            }
+
+            if step.kind == RuleKind::Memory {
+                if step.mem_is_write {
+                    last_write.insert(step.mem_addr, step.mem_value);
+                } else if let Some(&expected) = last_write.get(&step.mem_addr) {
+                    if expected != step.mem_value {
+                        return Err(SosR1CSError::MemoryOrderingViolation(i));
+                    }
+                }
+            }
+
+            if i + 1 < trace.len() && step.next_pc != trace[i + 1].pc {
+                return Err(SosR1CSError::PcDiscontinuity(i));
+            }
+
+            witnesses.push(z);
+        }
+
+        Ok(witnesses)
+    }
+
+    /// Verifies the repeated single-step system against a Fiat-Shamir
+    /// random linear combination across steps, the same batching
+    /// [`super::uniform_r1cs::UniformR1CS::verify`] uses.
+    pub fn verify(&self, witnesses: &[Vec<Fr>], r: Fr) -> bool {
+        let (a, b, c) = self.synthesize_step();
+        verify_batched(&a, &b, &c, witnesses, r)
+    }
+}
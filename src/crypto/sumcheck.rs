@@ -0,0 +1,292 @@
+use crate::crypto::proof::generator::Transcript;
+use crate::field::Fr;
+
+/// A multilinear polynomial given by its evaluations over the boolean
+/// hypercube `{0,1}^n`. `evaluations[i]` is `f(b_{n-1}, ..., b_0)` where
+/// `b_{n-1}...b_0` is the binary representation of `i` (MSB-first variable
+/// ordering, matching the round order the sum-check prover below consumes).
+#[derive(Clone, Debug)]
+pub struct MultilinearPolynomial {
+    evaluations: Vec<Fr>,
+    num_vars: usize,
+}
+
+impl MultilinearPolynomial {
+    pub fn new(evaluations: Vec<Fr>) -> Self {
+        let len = evaluations.len();
+        assert!(len.is_power_of_two(), "evaluations must have length 2^n");
+        MultilinearPolynomial {
+            num_vars: len.trailing_zeros() as usize,
+            evaluations,
+        }
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    pub fn evaluations(&self) -> &[Fr] {
+        &self.evaluations
+    }
+
+    /// Evaluates the multilinear extension at an arbitrary point
+    /// `(r_0, ..., r_{n-1}) in Fr^n` via repeated linear interpolation
+    /// (the same folding sum-check uses, run to completion).
+    pub fn evaluate(&self, point: &[Fr]) -> Fr {
+        assert_eq!(point.len(), self.num_vars);
+        let mut table = self.evaluations.clone();
+        for &r in point {
+            table = fold(&table, r);
+        }
+        table[0]
+    }
+
+    /// Binds the first free variable to `value`, halving the evaluation
+    /// table (the per-round update the sum-check prover performs).
+    pub fn fix_variable(&self, value: Fr) -> MultilinearPolynomial {
+        MultilinearPolynomial {
+            evaluations: fold(&self.evaluations, value),
+            num_vars: self.num_vars - 1,
+        }
+    }
+}
+
+fn fold(table: &[Fr], r: Fr) -> Vec<Fr> {
+    let half = table.len() / 2;
+    (0..half)
+        .map(|i| table[i] + r * (table[half + i] - table[i]))
+        .collect()
+}
+
+/// One round of the sum-check protocol: the prover's univariate `g_j`,
+/// represented by its evaluations at `0, 1, ..., degree` (Lagrange form,
+/// per the protocol's usual presentation, rather than coefficient form).
+#[derive(Clone, Debug)]
+pub struct SumCheckRound {
+    pub evaluations: Vec<Fr>,
+}
+
+impl SumCheckRound {
+    fn eval_at(&self, x: Fr) -> Fr {
+        lagrange_interpolate(&self.evaluations, x)
+    }
+
+    fn sum_at_bits(&self) -> Fr {
+        self.evaluations[0] + self.evaluations[1]
+    }
+}
+
+/// Evaluates the degree-`evals.len() - 1` polynomial through
+/// `(0, evals[0]), (1, evals[1]), ...` at `x`.
+fn lagrange_interpolate(evals: &[Fr], x: Fr) -> Fr {
+    let n = evals.len();
+    let mut result = Fr::zero();
+    for i in 0..n {
+        let xi = Fr::from(i as u64);
+        let mut num = Fr::one();
+        let mut den = Fr::one();
+        for j in 0..n {
+            if i == j {
+                continue;
+            }
+            let xj = Fr::from(j as u64);
+            num *= x - xj;
+            den *= xi - xj;
+        }
+        result += evals[i] * num * den.inverse().expect("interpolation nodes are distinct");
+    }
+    result
+}
+
+#[derive(Clone, Debug)]
+pub struct SumCheckProof {
+    pub claimed_sum: Fr,
+    pub rounds: Vec<SumCheckRound>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SumCheckError {
+    RoundSumMismatch(usize),
+    FinalEvaluationMismatch,
+}
+
+/// Proves `sum_{x in {0,1}^n} poly(x) == claimed_sum`, squeezing one
+/// challenge per round from `transcript`. Returns the proof together with
+/// the challenge point `r` the verifier must use to check the final oracle
+/// evaluation `poly(r)`. A thin wrapper over [`prove_generic`] for the
+/// common single-multilinear, degree-1 case.
+pub fn prove<T: Transcript>(
+    poly: &MultilinearPolynomial,
+    transcript: &mut T,
+) -> (SumCheckProof, Vec<Fr>) {
+    prove_generic(&[poly.clone()], 1, |values| values[0], transcript)
+}
+
+/// Replays the transcript and checks every round's consistency relation
+/// `g_j(0) + g_j(1) == claim`. `final_eval` is `poly(r)`, obtained by the
+/// caller from a polynomial-commitment opening (sum-check itself reduces
+/// the claim to that single oracle query, it doesn't discharge it).
+pub fn verify<T: Transcript>(
+    proof: &SumCheckProof,
+    transcript: &mut T,
+    final_eval: Fr,
+) -> Result<Vec<Fr>, SumCheckError> {
+    verify_generic(proof, transcript, final_eval)
+}
+
+/// Folds a round's evaluations into `transcript`, one scalar per point —
+/// the `Transcript` trait only exposes per-`Fr` absorption, so a round
+/// polynomial (given by several evaluation points) is absorbed point by
+/// point under labels distinguished by index.
+fn absorb_round<T: Transcript>(transcript: &mut T, label: &str, round: &SumCheckRound) {
+    for (i, value) in round.evaluations.iter().enumerate() {
+        transcript.append_scalar(&format!("{label}_{i}"), value);
+    }
+}
+
+/// Proves `sum_{x in {0,1}^n} combine(p_0(x), ..., p_{k-1}(x)) == claimed_sum`
+/// for any combining function of the listed multilinear polynomials'
+/// evaluations, as long as `combine` has degree `<= degree` in each
+/// variable (e.g. `degree = 2` for a product of two polynomials). Since
+/// every input is multilinear, folding it at `X` is affine — `combine`
+/// only needs evaluating at the `degree + 1` integer points `0..=degree`
+/// to pin down each round's univariate exactly.
+pub fn prove_generic<F, T: Transcript>(
+    polys: &[MultilinearPolynomial],
+    degree: usize,
+    combine: F,
+    transcript: &mut T,
+) -> (SumCheckProof, Vec<Fr>)
+where
+    F: Fn(&[Fr]) -> Fr,
+{
+    let num_vars = polys[0].num_vars();
+    assert!(polys.iter().all(|p| p.num_vars() == num_vars));
+
+    let mut tables: Vec<Vec<Fr>> = polys.iter().map(|p| p.evaluations().to_vec()).collect();
+    let mut rounds = Vec::with_capacity(num_vars);
+    let mut challenges = Vec::with_capacity(num_vars);
+
+    let claimed_sum = combined_sum(&tables, &combine);
+
+    for _ in 0..num_vars {
+        let half = tables[0].len() / 2;
+        let evaluations: Vec<Fr> = (0..=degree)
+            .map(|x| {
+                let x_fr = Fr::from(x as u64);
+                let mut acc = Fr::zero();
+                let mut point = vec![Fr::zero(); tables.len()];
+                for i in 0..half {
+                    for (slot, table) in point.iter_mut().zip(tables.iter()) {
+                        *slot = table[i] + x_fr * (table[half + i] - table[i]);
+                    }
+                    acc += combine(&point);
+                }
+                acc
+            })
+            .collect();
+
+        let round = SumCheckRound { evaluations };
+        absorb_round(transcript, "sumcheck_round", &round);
+        rounds.push(round);
+
+        let r = transcript.challenge_scalar("sumcheck_challenge");
+        challenges.push(r);
+        for table in tables.iter_mut() {
+            *table = fold(table, r);
+        }
+    }
+
+    (SumCheckProof { claimed_sum, rounds }, challenges)
+}
+
+fn combined_sum<F>(tables: &[Vec<Fr>], combine: &F) -> Fr
+where
+    F: Fn(&[Fr]) -> Fr,
+{
+    let n = tables[0].len();
+    let mut point = vec![Fr::zero(); tables.len()];
+    let mut acc = Fr::zero();
+    for i in 0..n {
+        for (slot, table) in point.iter_mut().zip(tables.iter()) {
+            *slot = table[i];
+        }
+        acc += combine(&point);
+    }
+    acc
+}
+
+/// Replays the transcript and checks every round's consistency relation
+/// `g_j(0) + g_j(1) == claim`, for a round polynomial of any degree
+/// (interpolated through its `evaluations`). `final_eval` is
+/// `combine(p_0(r), ..., p_{k-1}(r))`, obtained by the caller from
+/// commitment openings of each input polynomial at the returned point.
+pub fn verify_generic<T: Transcript>(
+    proof: &SumCheckProof,
+    transcript: &mut T,
+    final_eval: Fr,
+) -> Result<Vec<Fr>, SumCheckError> {
+    let mut claim = proof.claimed_sum;
+    let mut challenges = Vec::with_capacity(proof.rounds.len());
+
+    for (i, round) in proof.rounds.iter().enumerate() {
+        if round.sum_at_bits() != claim {
+            return Err(SumCheckError::RoundSumMismatch(i));
+        }
+        absorb_round(transcript, "sumcheck_round", round);
+        let r = transcript.challenge_scalar("sumcheck_challenge");
+        claim = round.eval_at(r);
+        challenges.push(r);
+    }
+
+    if claim != final_eval {
+        return Err(SumCheckError::FinalEvaluationMismatch);
+    }
+
+    Ok(challenges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::proof::generator::PoseidonTranscript;
+
+    #[test]
+    fn multilinear_evaluate_matches_fix_variable_to_completion() {
+        let poly = MultilinearPolynomial::new(vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)]);
+        let point = vec![Fr::from(5), Fr::from(7)];
+
+        let via_evaluate = poly.evaluate(&point);
+        let via_fix = poly.fix_variable(point[0]).fix_variable(point[1]).evaluations()[0];
+
+        assert_eq!(via_evaluate, via_fix);
+    }
+
+    #[test]
+    fn prove_verify_round_trips_on_honest_proof() {
+        let poly = MultilinearPolynomial::new(vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)]);
+
+        let mut prover_transcript = PoseidonTranscript::new();
+        let (proof, challenges) = prove(&poly, &mut prover_transcript);
+        let final_eval = poly.evaluate(&challenges);
+
+        let mut verifier_transcript = PoseidonTranscript::new();
+        let verified_challenges = verify(&proof, &mut verifier_transcript, final_eval)
+            .expect("honest proof must verify");
+
+        assert_eq!(verified_challenges, challenges);
+    }
+
+    #[test]
+    fn verify_rejects_wrong_final_evaluation() {
+        let poly = MultilinearPolynomial::new(vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)]);
+
+        let mut prover_transcript = PoseidonTranscript::new();
+        let (proof, _) = prove(&poly, &mut prover_transcript);
+
+        let mut verifier_transcript = PoseidonTranscript::new();
+        let result = verify(&proof, &mut verifier_transcript, Fr::from(999));
+
+        assert_eq!(result, Err(SumCheckError::FinalEvaluationMismatch));
+    }
+}
@@ -0,0 +1,142 @@
+use super::hash::PoseidonHash;
+use crate::field::Fr;
+
+/// A universal structured reference string, in the spirit of Marlin/
+/// Sonic's `UniversalSRS`: generated once up to `max_degree` and then
+/// [`specialize`](Self::specialize)d (trimmed) to whatever smaller degree
+/// a given instruction actually needs, so every commitment scheme
+/// `ProofGenerator` builds — `poly_commit`, `kzg_commit`, `pedersen_commit`,
+/// `multilinear_kzg` — shares one set of powers-of-tau instead of each
+/// sampling its own secret independently.
+///
+/// `powers_g1[i]` stands in for `[tau^i]_1` and `tau_g2` for `[tau]_2`,
+/// both as bare `Fr` values per this crate's convention elsewhere
+/// (`commit_msm`, `VerifyingKey`) of representing "group elements" as
+/// field elements rather than real BN254/BLS12 curve points.
+pub struct UniversalSrs {
+    powers_g1: Vec<Fr>,
+    tau_g2: Fr,
+    max_degree: usize,
+}
+
+/// Proof of knowledge of one `contribute` step: `delta_g1`/`delta_g2` are
+/// the fresh secret `delta`'s contribution in each group, kept so
+/// [`UniversalSrs::verify_contribution`] can check the next SRS really is
+/// the previous one scaled by *some* `delta` — without ever learning
+/// `delta` itself — the same role a real ceremony's single KZG-style
+/// consistency pairing plays.
+#[derive(Clone, Debug)]
+pub struct ContributionProof {
+    pub delta_g1: Fr,
+    pub delta_g2: Fr,
+}
+
+impl UniversalSrs {
+    /// Samples a fresh secret `tau` and builds `powers_g1 = [tau^0, ...,
+    /// tau^max_degree]` plus `tau_g2 = tau` (a real setup would discard
+    /// `tau` itself immediately after; there's nothing here to discard
+    /// it *from* since this struct only ever stores the derived powers).
+    pub fn setup(max_degree: usize) -> Self {
+        let mut rng = rand::thread_rng();
+        let tau = Fr::random(&mut rng);
+        let powers_g1 = (0..=max_degree).map(|i| tau.pow(i as u64)).collect();
+        UniversalSrs { powers_g1, tau_g2: tau, max_degree }
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    /// One step of an updatable powers-of-tau ceremony: folds `entropy`
+    /// in as a fresh secret `delta`, updating `tau <- tau * delta` without
+    /// ever reconstructing `tau` itself — `powers_g1[i] <- powers_g1[i] *
+    /// delta^i` is exactly `[tau^i]_1 -> [(tau*delta)^i]_1` applied
+    /// entrywise, and `tau_g2 <- tau_g2 * delta` is the matching `[tau]_2
+    /// -> [tau*delta]_2` update. Returns the contribution's proof of
+    /// knowledge so a third party can later check this step via
+    /// [`Self::verify_contribution`] without trusting the contributor.
+    pub fn contribute(&mut self, entropy: Fr) -> ContributionProof {
+        let delta = PoseidonHash::new().hash(&[entropy, Fr::from(self.max_degree as u64)]);
+
+        let mut power = Fr::one();
+        for g1 in self.powers_g1.iter_mut() {
+            *g1 *= power;
+            power *= delta;
+        }
+        self.tau_g2 *= delta;
+
+        ContributionProof { delta_g1: delta, delta_g2: delta }
+    }
+
+    /// Checks `next` really is `prev` scaled by the `delta`
+    /// `proof` claims knowledge of, without needing either SRS's secret
+    /// `tau`: `e([tau]_1, [delta]_2) == e([tau*delta]_1, [1]_2)` reduces,
+    /// under this crate's `Fr`-valued stand-in for a pairing (see
+    /// `commit_msm`), to the two equalities checked below — a real
+    /// deployment would check this via an actual `ecPairing` call instead.
+    pub fn verify_contribution(prev: &UniversalSrs, next: &UniversalSrs, proof: &ContributionProof) -> bool {
+        if prev.max_degree != next.max_degree || proof.delta_g1 != proof.delta_g2 {
+            return false;
+        }
+        if next.tau_g2 != prev.tau_g2 * proof.delta_g1 {
+            return false;
+        }
+
+        let mut power = Fr::one();
+        for (p, n) in prev.powers_g1.iter().zip(next.powers_g1.iter()) {
+            if *n != *p * power {
+                return false;
+            }
+            power *= proof.delta_g1;
+        }
+        true
+    }
+
+    /// Validates a whole ceremony transcript: `chain[0]` is the initial
+    /// setup, `chain[i+1]` is `chain[i]` after `proofs[i]`'s contribution.
+    pub fn verify_transcript(chain: &[UniversalSrs], proofs: &[ContributionProof]) -> bool {
+        if chain.len() != proofs.len() + 1 {
+            return false;
+        }
+        chain
+            .windows(2)
+            .zip(proofs.iter())
+            .all(|(pair, proof)| Self::verify_contribution(&pair[0], &pair[1], proof))
+    }
+
+    /// Trims the universal SRS down to exactly the powers a degree-`degree`
+    /// univariate commitment (`kzg_commit`, `poly_commit`, or
+    /// `pedersen_commit`'s generator vector) needs.
+    pub fn specialize(&self, degree: usize) -> TrimmedSrs {
+        assert!(degree <= self.max_degree, "degree exceeds universal SRS bound");
+        TrimmedSrs {
+            powers_g1: self.powers_g1[..=degree].to_vec(),
+            tau_g2: self.tau_g2,
+        }
+    }
+
+    /// Derives `num_vars` independent-looking multivariate coordinates
+    /// `(tau_1, ..., tau_num_vars)` for `MultilinearKZG::from_tau` from the
+    /// same universal powers, so the multilinear scheme draws from the
+    /// one shared setup too instead of sampling its own secret. This is a
+    /// simplification: a real universal setup for a multilinear/sumcheck
+    /// commitment needs its own product structure, not a univariate
+    /// powers-of-tau slice, so each coordinate is re-hashed off a distinct
+    /// power rather than reused directly, to avoid the multivariate point
+    /// collapsing onto the univariate one's algebraic relations.
+    pub fn specialize_multilinear(&self, num_vars: usize) -> Vec<Fr> {
+        assert!(num_vars <= self.max_degree, "num_vars exceeds universal SRS bound");
+        (1..=num_vars)
+            .map(|i| PoseidonHash::new().hash(&[self.powers_g1[i], Fr::from(i as u64)]))
+            .collect()
+    }
+}
+
+/// A `UniversalSrs` trimmed to one fixed degree: exactly the shape
+/// `PedersenCommitment`/a univariate `KZGCommitment` needs as its
+/// generator vector.
+#[derive(Clone, Debug)]
+pub struct TrimmedSrs {
+    pub powers_g1: Vec<Fr>,
+    pub tau_g2: Fr,
+}
@@ -0,0 +1,238 @@
+use super::Fr;
+use crate::multicore::Worker;
+
+/// Errors that can occur while constructing or using an [`EvaluationDomain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DomainError {
+    /// The requested domain size exceeds the field's two-adicity (`Fr::S`).
+    PolynomialDegreeTooLarge,
+}
+
+/// A radix-2 evaluation domain over [`Fr`], mirroring bellman's `domain.rs`.
+///
+/// `Fr::MODULUS - 1 = 2^32 * odd`, so the multiplicative group of `Fr` has a
+/// subgroup of every order `2^exp` for `exp <= 32`. This type caches the
+/// generator of that subgroup (and its inverse) so callers get O(n log n)
+/// forward/inverse FFTs instead of paying for dense polynomial arithmetic.
+pub struct EvaluationDomain {
+    /// The domain size, always a power of two.
+    pub m: u64,
+    /// `log2(m)`.
+    pub exp: u32,
+    /// A primitive `m`-th root of unity.
+    pub omega: Fr,
+    /// `omega.inverse()`.
+    pub omegainv: Fr,
+    /// The inverse of the field's multiplicative generator, used for coset FFTs.
+    pub geninv: Fr,
+    /// `Fr::from(m).inverse()`.
+    pub minv: Fr,
+}
+
+impl EvaluationDomain {
+    /// Builds a domain large enough to hold `needed` evaluations, rounding up
+    /// to the next power of two.
+    pub fn new(needed: usize) -> Result<Self, DomainError> {
+        let mut m = 1u64;
+        let mut exp = 0u32;
+        while (m as usize) < needed {
+            m <<= 1;
+            exp += 1;
+            if exp > Fr::S {
+                return Err(DomainError::PolynomialDegreeTooLarge);
+            }
+        }
+
+        // omega = g^((p-1)/m), a primitive m-th root of unity.
+        let mut omega = Fr::root_of_unity();
+        for _ in exp..Fr::S {
+            omega = omega * omega;
+        }
+
+        Ok(EvaluationDomain {
+            m,
+            exp,
+            omega,
+            omegainv: omega.inverse().expect("omega is nonzero"),
+            geninv: Fr::multiplicative_generator()
+                .inverse()
+                .expect("generator is nonzero"),
+            minv: Fr::from(m).inverse().expect("m is nonzero mod p"),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.m as usize
+    }
+
+    /// The vanishing polynomial of this domain evaluated at `tau`: `tau^m - 1`.
+    pub fn z(&self, tau: Fr) -> Fr {
+        tau.pow(self.m) - Fr::one()
+    }
+
+    /// In-place forward FFT: turns coefficients into evaluations over the domain.
+    pub fn fft(&self, values: &mut [Fr]) {
+        assert_eq!(values.len(), self.size());
+        Self::butterfly(values, self.omega);
+    }
+
+    /// In-place inverse FFT: turns evaluations back into coefficients.
+    pub fn ifft(&self, values: &mut [Fr]) {
+        assert_eq!(values.len(), self.size());
+        Self::butterfly(values, self.omegainv);
+        for value in values.iter_mut() {
+            *value *= self.minv;
+        }
+    }
+
+    /// Multiplies coefficient `i` by `generator^i`, shifting the domain to a coset
+    /// before forward-transforming, so evaluations land off the vanishing set.
+    pub fn coset_fft(&self, values: &mut [Fr]) {
+        Self::distribute_powers(values, Fr::multiplicative_generator());
+        self.fft(values);
+    }
+
+    /// Inverse of [`coset_fft`](Self::coset_fft): inverse-transforms then
+    /// un-shifts coefficient `i` by `geninv^i`.
+    pub fn icoset_fft(&self, values: &mut [Fr]) {
+        self.ifft(values);
+        Self::distribute_powers(values, self.geninv);
+    }
+
+    /// Multiplies two coefficient vectors by evaluating both on this
+    /// domain's coset (avoiding the vanishing set, so no aliasing between
+    /// the two polynomials' combined degree and `self.size()`), multiplying
+    /// pointwise, and inverse-transforming back off the coset. `a` and `b`
+    /// must already be built over a domain at least as large as
+    /// `a.len() + b.len() - 1`.
+    pub fn mul_polynomials(&self, a: &[Fr], b: &[Fr]) -> Vec<Fr> {
+        assert_eq!(a.len(), self.size());
+        assert_eq!(b.len(), self.size());
+
+        let mut a = a.to_vec();
+        let mut b = b.to_vec();
+        self.coset_fft(&mut a);
+        self.coset_fft(&mut b);
+        for (x, y) in a.iter_mut().zip(b.iter()) {
+            *x *= *y;
+        }
+        self.icoset_fft(&mut a);
+        a
+    }
+
+    fn distribute_powers(values: &mut [Fr], g: Fr) {
+        let mut power = Fr::one();
+        for value in values.iter_mut() {
+            *value *= power;
+            power *= g;
+        }
+    }
+
+    /// Parallel radix-2 forward FFT, splitting the coefficient array into
+    /// `worker.num_cpus()` chunks: each thread computes a sub-DFT over a
+    /// stride `2^log_cpus`, then the chunks are combined with twiddle
+    /// factors so the result matches the serial bit-reversal FFT exactly.
+    pub fn fft_parallel(&self, values: &mut [Fr], worker: &Worker) {
+        Self::parallel_butterfly(values, self.omega, worker);
+    }
+
+    /// Inverse of [`fft_parallel`](Self::fft_parallel).
+    pub fn ifft_parallel(&self, values: &mut [Fr], worker: &Worker) {
+        Self::parallel_butterfly(values, self.omegainv, worker);
+        let minv = self.minv;
+        worker.scope(|scope| {
+            let chunk_len = (values.len() / worker.num_cpus().max(1)).max(1);
+            for chunk in values.chunks_mut(chunk_len) {
+                scope.spawn(move |_| {
+                    for value in chunk.iter_mut() {
+                        *value *= minv;
+                    }
+                });
+            }
+        });
+    }
+
+    fn parallel_butterfly(values: &mut [Fr], omega: Fr, worker: &Worker) {
+        let n = values.len();
+        let log_n = n.trailing_zeros();
+        let log_cpus = worker.log_num_cpus().min(log_n);
+
+        if log_cpus == 0 || log_n == 0 {
+            Self::butterfly(values, omega);
+            return;
+        }
+
+        let num_cpus = 1usize << log_cpus;
+        let log_new_n = log_n - log_cpus;
+        let new_n = 1usize << log_new_n;
+        let new_omega = omega.pow(num_cpus as u64);
+
+        let mut tmp: Vec<Vec<Fr>> = vec![vec![Fr::zero(); new_n]; num_cpus];
+        let source: &[Fr] = values;
+
+        worker.scope(|scope| {
+            for (j, chunk) in tmp.iter_mut().enumerate() {
+                scope.spawn(move |_| {
+                    let omega_j = omega.pow(j as u64);
+                    let omega_step = omega.pow((j as u64) << log_new_n);
+
+                    let mut elt = Fr::one();
+                    for i in 0..new_n {
+                        for s in 0..num_cpus {
+                            let idx = (i + (s << log_new_n)) % n;
+                            chunk[i] += source[idx] * elt;
+                            elt *= omega_step;
+                        }
+                        elt *= omega_j;
+                    }
+                    Self::butterfly(chunk, new_omega);
+                });
+            }
+        });
+
+        let mask = num_cpus - 1;
+        for (idx, value) in values.iter_mut().enumerate() {
+            *value = tmp[idx & mask][idx >> log_cpus];
+        }
+    }
+
+    /// Iterative Cooley-Tukey butterfly with bit-reversal permutation.
+    fn butterfly(values: &mut [Fr], omega: Fr) {
+        let n = values.len();
+        let log_n = n.trailing_zeros();
+
+        for k in 0..n {
+            let rk = bitreverse(k as u32, log_n) as usize;
+            if k < rk {
+                values.swap(k, rk);
+            }
+        }
+
+        let mut m = 1usize;
+        while m < n {
+            let w_m = omega.pow((n / (2 * m)) as u64);
+            let mut k = 0;
+            while k < n {
+                let mut w = Fr::one();
+                for j in 0..m {
+                    let t = w * values[k + j + m];
+                    let u = values[k + j];
+                    values[k + j] = u + t;
+                    values[k + j + m] = u - t;
+                    w *= w_m;
+                }
+                k += 2 * m;
+            }
+            m *= 2;
+        }
+    }
+}
+
+fn bitreverse(mut n: u32, l: u32) -> u32 {
+    let mut r = 0u32;
+    for _ in 0..l {
+        r = (r << 1) | (n & 1);
+        n >>= 1;
+    }
+    r
+}
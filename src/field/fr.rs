@@ -1,12 +1,25 @@
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 use rand::RngCore;
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Fr(u64);
 
 impl Fr {
     pub const MODULUS: u64 = 0xFFFFFFFF00000001;
 
+    /// `MODULUS - 1` is divisible by `2^S` but not `2^(S+1)`, i.e. the field's 2-adicity.
+    pub const S: u32 = 32;
+
+    /// A multiplicative generator of `Fr*`.
+    pub fn multiplicative_generator() -> Self {
+        Fr::from(7)
+    }
+
+    /// A primitive `2^S`-th root of unity, `g^((p-1)/2^S)`.
+    pub fn root_of_unity() -> Self {
+        Self::multiplicative_generator().pow((Self::MODULUS - 1) >> Self::S)
+    }
+
     pub fn zero() -> Self {
         Fr(0)
     }
@@ -46,6 +59,20 @@ impl Fr {
     pub fn is_zero(&self) -> bool {
         self.0 == 0
     }
+
+    /// The canonical `u64` representative in `[0, MODULUS)`.
+    pub fn to_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// `0xFFFFFFFF`, i.e. `u64::MAX - MODULUS + 1`: the correction added back
+    /// when a `u64` operation wraps past the modulus.
+    const EPSILON: u64 = (1u64 << 32) - 1;
+
+    #[inline]
+    fn assert_canonical(&self) {
+        debug_assert!(self.0 < Self::MODULUS, "Fr value {} is not canonical", self.0);
+    }
 }
 
 impl From<u64> for Fr {
@@ -56,9 +83,19 @@ impl From<u64> for Fr {
 
 impl Add for Fr {
     type Output = Fr;
-    
+
     fn add(self, rhs: Fr) -> Fr {
-        Fr((self.0 + rhs.0) % Self::MODULUS)
+        self.assert_canonical();
+        rhs.assert_canonical();
+
+        let (sum, carry) = self.0.overflowing_add(rhs.0);
+        // Both operands are in [0, p), so the true sum is in [0, 2p); fold
+        // down by the single correction a carry or an overflow-past-p implies.
+        let (mut sum, over) = sum.overflowing_add(if carry { Self::EPSILON } else { 0 });
+        if over || sum >= Self::MODULUS {
+            sum = sum.wrapping_sub(Self::MODULUS);
+        }
+        Fr(sum)
     }
 }
 
@@ -70,13 +107,18 @@ impl AddAssign for Fr {
 
 impl Sub for Fr {
     type Output = Fr;
-    
+
     fn sub(self, rhs: Fr) -> Fr {
-        if self.0 >= rhs.0 {
-            Fr(self.0 - rhs.0)
+        self.assert_canonical();
+        rhs.assert_canonical();
+
+        let (diff, borrow) = self.0.overflowing_sub(rhs.0);
+        let diff = if borrow {
+            diff.wrapping_sub(Self::EPSILON)
         } else {
-            Fr(Self::MODULUS - (rhs.0 - self.0))
-        }
+            diff
+        };
+        Fr(diff)
     }
 }
 
@@ -88,9 +130,31 @@ impl SubAssign for Fr {
 
 impl Mul for Fr {
     type Output = Fr;
-    
+
     fn mul(self, rhs: Fr) -> Fr {
-        Fr(((self.0 as u128 * rhs.0 as u128) % Self::MODULUS as u128) as u64)
+        self.assert_canonical();
+        rhs.assert_canonical();
+
+        // Goldilocks reduction: x = lo + 2^64 * hi, hi = hi_hi * 2^32 + hi_lo,
+        // using 2^64 = 2^32 - 1 (mod p) and 2^96 = -1 (mod p).
+        let x = self.0 as u128 * rhs.0 as u128;
+        let lo = x as u64;
+        let hi = (x >> 64) as u64;
+        let hi_hi = hi >> 32;
+        let hi_lo = hi & Self::EPSILON;
+
+        let (t0, borrow) = lo.overflowing_sub(hi_hi);
+        let t0 = if borrow { t0.wrapping_sub(Self::EPSILON) } else { t0 };
+
+        let t1 = hi_lo * Self::EPSILON;
+
+        let (t2, carry) = t0.overflowing_add(t1);
+        let (mut t2, over) = t2.overflowing_add(if carry { Self::EPSILON } else { 0 });
+        if over || t2 >= Self::MODULUS {
+            t2 = t2.wrapping_sub(Self::MODULUS);
+        }
+
+        Fr(t2)
     }
 }
 
@@ -98,4 +162,26 @@ impl MulAssign for Fr {
     fn mul_assign(&mut self, rhs: Fr) {
         *self = *self * rhs;
     }
+}
+
+/// Inverts every element of `values` with a single field inversion: build
+/// the running product `prefix[i] = values[0]*...*values[i-1]`, invert the
+/// total product once, then back-substitute `result[i] = inv_total_to_i *
+/// prefix[i]`, peeling off one factor of `values[i]` per step. Panics if
+/// any value is zero.
+pub fn batch_inverse(values: &[Fr]) -> Vec<Fr> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Fr::one();
+    for &v in values {
+        prefix.push(acc);
+        acc *= v;
+    }
+
+    let mut acc_inv = acc.inverse().expect("batch_inverse: zero value in input");
+    let mut result = vec![Fr::zero(); values.len()];
+    for i in (0..values.len()).rev() {
+        result[i] = acc_inv * prefix[i];
+        acc_inv *= values[i];
+    }
+    result
 }
\ No newline at end of file
@@ -0,0 +1,139 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use super::Fr;
+
+/// `Fr2 = Fr[u] / (u^2 - NONRESIDUE)`. `7` — `Fr`'s own multiplicative
+/// generator — has no square root in `Fr`, the same non-residue choice
+/// plonky2's `QuadraticExtension<GoldilocksField>` makes for the same
+/// underlying field.
+const NONRESIDUE: u64 = 7;
+
+/// An element `a0 + a1*u` of `Fr`'s quadratic extension.
+///
+/// Used in place of a single `Fr` accumulator wherever the base field is
+/// small enough that the soundness error of a Fiat-Shamir challenge drawn
+/// from `Fr` alone — on the order of `trace_len / |Fr|` — stops being
+/// negligible: folding into `Fr2` instead drops that error to the order
+/// of `trace_len / |Fr|^2`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fr2 {
+    pub a0: Fr,
+    pub a1: Fr,
+}
+
+impl Fr2 {
+    pub fn new(a0: Fr, a1: Fr) -> Self {
+        Fr2 { a0, a1 }
+    }
+
+    /// Embeds a base-field element as `a + 0*u`.
+    pub fn from_base(a: Fr) -> Self {
+        Fr2 { a0: a, a1: Fr::zero() }
+    }
+
+    pub fn zero() -> Self {
+        Fr2 { a0: Fr::zero(), a1: Fr::zero() }
+    }
+
+    pub fn one() -> Self {
+        Fr2 { a0: Fr::one(), a1: Fr::zero() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.a0.is_zero() && self.a1.is_zero()
+    }
+
+    /// The two base-field coordinates `(a0, a1)` — what a proof carries as
+    /// its pair of accumulator columns instead of a single `Fr` column.
+    pub fn coordinates(&self) -> (Fr, Fr) {
+        (self.a0, self.a1)
+    }
+
+    /// The Galois conjugate `a0 - a1*u`, used by [`inverse`](Self::inverse).
+    fn conjugate(&self) -> Fr2 {
+        Fr2 { a0: self.a0, a1: Fr::zero() - self.a1 }
+    }
+
+    /// `N(a0+a1*u) = a0^2 - NONRESIDUE*a1^2`, a base-field element because
+    /// `self * self.conjugate() == Fr2::from_base(self.norm())`.
+    fn norm(&self) -> Fr {
+        self.a0 * self.a0 - Fr::from(NONRESIDUE) * self.a1 * self.a1
+    }
+
+    pub fn inverse(&self) -> Option<Fr2> {
+        let norm_inv = self.norm().inverse()?;
+        let conj = self.conjugate();
+        Some(Fr2 {
+            a0: conj.a0 * norm_inv,
+            a1: conj.a1 * norm_inv,
+        })
+    }
+}
+
+impl Add for Fr2 {
+    type Output = Fr2;
+    fn add(self, rhs: Fr2) -> Fr2 {
+        Fr2 { a0: self.a0 + rhs.a0, a1: self.a1 + rhs.a1 }
+    }
+}
+
+impl AddAssign for Fr2 {
+    fn add_assign(&mut self, rhs: Fr2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Fr2 {
+    type Output = Fr2;
+    fn sub(self, rhs: Fr2) -> Fr2 {
+        Fr2 { a0: self.a0 - rhs.a0, a1: self.a1 - rhs.a1 }
+    }
+}
+
+impl SubAssign for Fr2 {
+    fn sub_assign(&mut self, rhs: Fr2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Fr2 {
+    type Output = Fr2;
+    fn mul(self, rhs: Fr2) -> Fr2 {
+        // (a0 + a1*u)(b0 + b1*u) = (a0*b0 + NONRESIDUE*a1*b1) + (a0*b1 + a1*b0)*u
+        let a0 = self.a0 * rhs.a0 + Fr::from(NONRESIDUE) * self.a1 * rhs.a1;
+        let a1 = self.a0 * rhs.a1 + self.a1 * rhs.a0;
+        Fr2 { a0, a1 }
+    }
+}
+
+impl MulAssign for Fr2 {
+    fn mul_assign(&mut self, rhs: Fr2) {
+        *self = *self * rhs;
+    }
+}
+
+impl From<Fr> for Fr2 {
+    fn from(value: Fr) -> Self {
+        Fr2::from_base(value)
+    }
+}
+
+/// Inverts every element of `values` with a single [`Fr`] inversion,
+/// mirroring [`super::batch_inverse`]'s running-product trick lifted to
+/// `Fr2`. Panics if any value is zero.
+pub fn batch_inverse(values: &[Fr2]) -> Vec<Fr2> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Fr2::one();
+    for &v in values {
+        prefix.push(acc);
+        acc *= v;
+    }
+
+    let mut acc_inv = acc.inverse().expect("batch_inverse: zero value in input");
+    let mut result = vec![Fr2::zero(); values.len()];
+    for i in (0..values.len()).rev() {
+        result[i] = acc_inv * prefix[i];
+        acc_inv *= values[i];
+    }
+    result
+}
@@ -0,0 +1,7 @@
+mod fr;
+mod fr2;
+mod domain;
+
+pub use fr::{batch_inverse, Fr};
+pub use fr2::{batch_inverse as batch_inverse_ext, Fr2};
+pub use domain::{EvaluationDomain, DomainError};
@@ -0,0 +1,76 @@
+use crate::field::Fr;
+
+/// A fault that stops normal instruction execution and hands control to
+/// `TrapHandler` instead: decoding raises `IllegalInstruction` for an
+/// instruction word it can't parse, and a memory hierarchy raises the
+/// misaligned/out-of-bounds variants, so both failure paths land in the
+/// same constrained trap-transition machinery rather than one aborting and
+/// the other erroring out silently.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trap {
+    IllegalInstruction,
+    MisalignedAccess { addr: u64 },
+    OutOfBoundsAccess { addr: u64 },
+    Ecall,
+    Ebreak,
+    DivisionByZero,
+}
+
+/// The commitments `TrapHandler::handle` returns for a trap transition: to
+/// the frozen `pc`, to the `cause` code, and a zero placeholder standing
+/// for "nothing else in `ExecutionWitness` mutated". No constraint over
+/// them is checked here.
+#[derive(Debug, Clone)]
+pub struct TrapProof {
+    pub pc_commitment: Fr,
+    pub cause_commitment: Fr,
+    pub no_mutation_commitment: Fr,
+}
+
+/// Just enough of a CPU step for `TrapHandler` (and
+/// `ProofGenerator::generate_trap_proof`) to constrain a trap transition
+/// without duplicating the full per-step R1CS layout
+/// `crate::crypto::uniform_r1cs::Step` uses for non-trapping steps.
+#[derive(Clone, Debug)]
+pub struct ExecutionWitness {
+    pub pc: u64,
+    pub rd: u8,
+    pub rd_value: u64,
+    pub mem_addr: u64,
+    pub mem_value: u64,
+    pub divisor: u64,
+}
+
+/// Produces the constrained trap transition `ProofGenerator` folds into a
+/// `SemanticProof` when a step traps instead of executing normally: PC
+/// frozen, cause register set, no other state mutated.
+pub struct TrapHandler;
+
+impl TrapHandler {
+    pub fn new() -> Self {
+        TrapHandler
+    }
+
+    /// Builds the trap transition for `witness` trapping with `cause`.
+    /// The commitments are to the frozen PC, the cause code, and a
+    /// zero value standing in for "no other cell mutated" — a real
+    /// implementation would commit to the full pre/post state and prove
+    /// their equality, but that's exactly the per-step R1CS argument
+    /// `crate::crypto::uniform_r1cs` already makes for ordinary steps.
+    pub fn handle(&self, witness: &ExecutionWitness, cause: Trap) -> TrapProof {
+        let cause_code = match cause {
+            Trap::IllegalInstruction => 1,
+            Trap::MisalignedAccess { .. } => 2,
+            Trap::OutOfBoundsAccess { .. } => 3,
+            Trap::Ecall => 4,
+            Trap::Ebreak => 5,
+            Trap::DivisionByZero => 6,
+        };
+
+        TrapProof {
+            pc_commitment: Fr::from(witness.pc),
+            cause_commitment: Fr::from(cause_code),
+            no_mutation_commitment: Fr::zero(),
+        }
+    }
+}
@@ -1,18 +1,22 @@
 use crate::field::Fr;
-use rayon::prelude::*;
+use crate::multicore::Worker;
 use std::sync::atomic::{fence, Ordering};
 
 /// SIMD Vector Processing Unit with constant-time operations
 pub struct VectorUnit {
     // Vector registers
     registers: Vec<VectorRegister>,
-    
+
     // Mask registers for predicated execution
     mask_registers: Vec<VectorMask>,
-    
+
     // Configuration
     vector_length: usize,
     max_elements: usize,
+
+    // Shared parallelism budget, the same `Worker` the NTT uses, instead of
+    // an implicit global rayon pool.
+    worker: Worker,
 }
 
 #[derive(Clone)]
@@ -42,33 +46,43 @@ impl VectorUnit {
             mask_registers: vec![VectorMask::new(vector_length); 8],
             vector_length,
             max_elements: vector_length,
+            worker: Worker::new(),
         }
     }
 
     /// Vector arithmetic operations
-    pub fn vector_add(&mut self, vd: usize, vs1: usize, vs2: usize, mask: usize) 
-        -> Result<(), VectorError> 
+    pub fn vector_add(&mut self, vd: usize, vs1: usize, vs2: usize, mask: usize)
+        -> Result<(), VectorError>
     {
         self.check_register_indices(&[vd, vs1, vs2])?;
         let mask_reg = &self.mask_registers[mask];
-        
+
         // Constant-time vector addition
         fence(Ordering::SeqCst);
-        
+
         let vs1_reg = &self.registers[vs1];
         let vs2_reg = &self.registers[vs2];
         let vd_reg = &mut self.registers[vd];
-        
-        vd_reg.elements.par_iter_mut()
-            .zip(vs1_reg.elements.par_iter())
-            .zip(vs2_reg.elements.par_iter())
-            .zip(mask_reg.mask.par_iter())
-            .for_each(|(((vd_elem, &vs1_elem), &vs2_elem), &mask)| {
-                if mask {
-                    *vd_elem = vs1_elem + vs2_elem;
-                }
-            });
-            
+
+        let vs1_elems = &vs1_reg.elements;
+        let vs2_elems = &vs2_reg.elements;
+        let mask_bits = &mask_reg.mask;
+        let chunk_len = (vd_reg.elements.len() / self.worker.num_cpus().max(1)).max(1);
+
+        self.worker.scope(|scope| {
+            for (chunk_idx, chunk) in vd_reg.elements.chunks_mut(chunk_len).enumerate() {
+                let start = chunk_idx * chunk_len;
+                scope.spawn(move |_| {
+                    for (i, vd_elem) in chunk.iter_mut().enumerate() {
+                        let idx = start + i;
+                        if mask_bits[idx] {
+                            *vd_elem = vs1_elems[idx] + vs2_elems[idx];
+                        }
+                    }
+                });
+            }
+        });
+
         Ok(())
     }
 
@@ -140,41 +154,46 @@ impl VectorUnit {
         Ok(())
     }
 
-    /// Vector reduction operations
-    pub fn vector_reduce(&self, vs: usize, op: ReduceOp, mask: usize) 
+    /// Vector reduction operations, combining one partial reduction per
+    /// chunk of `len / worker.num_cpus()` elements (computed on the shared
+    /// worker pool; there can be more chunks than CPUs) in a final serial
+    /// fold.
+    pub fn vector_reduce(&self, vs: usize, op: ReduceOp, mask: usize)
         -> Result<Fr, VectorError>
     {
         self.check_register_indices(&[vs])?;
         let mask_reg = &self.mask_registers[mask];
         let vs_reg = &self.registers[vs];
-        
-        let result = match op {
-            ReduceOp::Sum => vs_reg.elements.par_iter()
-                .zip(mask_reg.mask.par_iter())
-                .filter(|(_, &mask)| mask)
-                .map(|(&elem, _)| elem)
-                .reduce(|| Fr::zero(), |a, b| a + b),
-                
-            ReduceOp::Product => vs_reg.elements.par_iter()
-                .zip(mask_reg.mask.par_iter())
-                .filter(|(_, &mask)| mask)
-                .map(|(&elem, _)| elem)
-                .reduce(|| Fr::one(), |a, b| a * b),
-                
-            ReduceOp::Max => vs_reg.elements.par_iter()
-                .zip(mask_reg.mask.par_iter())
-                .filter(|(_, &mask)| mask)
-                .map(|(&elem, _)| elem)
-                .reduce(|| Fr::min_value(), |a, b| Fr::max(a, b)),
-                
-            ReduceOp::Min => vs_reg.elements.par_iter()
-                .zip(mask_reg.mask.par_iter())
-                .filter(|(_, &mask)| mask)
-                .map(|(&elem, _)| elem)
-                .reduce(|| Fr::max_value(), |a, b| Fr::min(a, b)),
+
+        let (identity, combine): (Fr, fn(Fr, Fr) -> Fr) = match op {
+            ReduceOp::Sum => (Fr::zero(), |a, b| a + b),
+            ReduceOp::Product => (Fr::one(), |a, b| a * b),
+            ReduceOp::Max => (Fr::zero(), |a, b| if b > a { b } else { a }),
+            ReduceOp::Min => (Fr::zero(), |a, b| if b < a { b } else { a }),
         };
-        
-        Ok(result)
+
+        let num_chunks = self.worker.num_cpus().max(1);
+        let chunk_len = (vs_reg.elements.len() / num_chunks).max(1);
+        let actual_chunks = vs_reg.elements.chunks(chunk_len).count();
+        let mut partials = vec![identity; actual_chunks];
+
+        self.worker.scope(|scope| {
+            for (chunk_idx, (partial, (elem_chunk, mask_chunk))) in partials.iter_mut()
+                .zip(vs_reg.elements.chunks(chunk_len).zip(mask_reg.mask.chunks(chunk_len)))
+                .enumerate()
+            {
+                let _ = chunk_idx;
+                scope.spawn(move |_| {
+                    for (&elem, &masked) in elem_chunk.iter().zip(mask_chunk.iter()) {
+                        if masked {
+                            *partial = combine(*partial, elem);
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(partials.into_iter().fold(identity, combine))
     }
 
     /// Vector permutation with bounds checking
@@ -183,23 +202,40 @@ impl VectorUnit {
     {
         self.check_register_indices(&[vd, vs])?;
         let mask_reg = &self.mask_registers[mask];
-        
+
         let vs_reg = &self.registers[vs];
         let vd_reg = &mut self.registers[vd];
-        
-        vd_reg.elements.par_iter_mut()
-            .zip(indices.par_iter())
-            .zip(mask_reg.mask.par_iter())
-            .try_for_each(|((vd_elem, &idx), &mask)| {
-                if mask {
-                    if idx >= self.vector_length {
-                        return Err(VectorError::IndexOutOfBounds);
+        let vector_length = self.vector_length;
+
+        let vs_elems = &vs_reg.elements;
+        let mask_bits = &mask_reg.mask;
+        let chunk_len = (vd_reg.elements.len() / self.worker.num_cpus().max(1)).max(1);
+
+        let error = std::sync::Mutex::new(None);
+        self.worker.scope(|scope| {
+            for (chunk_idx, chunk) in vd_reg.elements.chunks_mut(chunk_len).enumerate() {
+                let start = chunk_idx * chunk_len;
+                let error = &error;
+                scope.spawn(move |_| {
+                    for (i, vd_elem) in chunk.iter_mut().enumerate() {
+                        let idx = start + i;
+                        if mask_bits[idx] {
+                            let src = indices[idx];
+                            if src >= vector_length {
+                                *error.lock().unwrap() = Some(VectorError::IndexOutOfBounds);
+                                return;
+                            }
+                            *vd_elem = vs_elems[src];
+                        }
                     }
-                    *vd_elem = vs_reg.elements[idx];
-                }
-                Ok(())
-            })?;
-            
+                });
+            }
+        });
+
+        if let Some(err) = error.into_inner().unwrap() {
+            return Err(err);
+        }
+
         Ok(())
     }
 
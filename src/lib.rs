@@ -4,6 +4,16 @@ pub mod register;
 pub mod memory;
 pub mod execution;
 pub mod utils;
+pub mod field;
+pub mod multicore;
+pub mod crypto;
+pub mod zk;
+// `semantics`, `verification`, `optimization`, `optimizer`, and
+// `jolt_instructions` are unfinished skeletons (undefined helper
+// types/methods throughout, plus a `target-lexicon` version conflict and
+// an object-safety error in `optimizer::jit`) that nothing in `crypto`/`zk`
+// depends on. Left unwired until they're fixed rather than shipped as a
+// crate that doesn't build.
 
 pub use crate::core::*;
 pub use crate::instructions::*;
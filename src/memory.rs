@@ -1,9 +1,15 @@
+use crate::crypto::merkle::SparseMerkleTree;
+use crate::field::Fr;
 use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Memory {
     data: HashMap<u64, u64>,
     width: u8,
+    /// A sparse Merkle commitment to `data`, kept in lockstep with it:
+    /// every `write` updates both, so `root()` is always the Poseidon root
+    /// of the memory `write` has built up so far.
+    tree: SparseMerkleTree,
 }
 
 impl Memory {
@@ -11,6 +17,7 @@ impl Memory {
         Memory {
             data: HashMap::new(),
             width,
+            tree: SparseMerkleTree::new(),
         }
     }
 
@@ -19,10 +26,27 @@ impl Memory {
     }
 
     pub fn write(&mut self, address: u64, value: u64) {
-        self.data.insert(address, value & ((1u64 << self.width) - 1));
+        let masked = value & ((1u64 << self.width) - 1);
+        self.data.insert(address, masked);
+        self.tree.update(address, Fr::from(masked));
+    }
+
+    /// The current Poseidon root committing to every write `Memory` has
+    /// recorded, for `ProofGenerator::prove_memory_accesses` to bind a
+    /// step's pre/post state to.
+    pub fn root(&self) -> Fr {
+        self.tree.root()
+    }
+
+    /// The sparse Merkle tree backing `root()`, for generating the
+    /// pre/post-state [`crate::crypto::merkle::SparseMerkleProof`]s an
+    /// execution-trace consistency proof needs.
+    pub fn tree(&self) -> &SparseMerkleTree {
+        &self.tree
     }
 
     pub fn clear(&mut self) {
         self.data.clear();
+        self.tree = SparseMerkleTree::new();
     }
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+//! A small `bellman`-style multicore abstraction so the whole crate shares a
+//! single, configurable parallelism budget instead of mixing an implicit
+//! global rayon pool with ad-hoc recursion.
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+/// Returns `log2` of the number of logical CPUs available (counted as at
+/// least `1`), rounded down, so `0` on a single-CPU machine.
+pub fn log_num_cpus() -> u32 {
+    let cpus = num_cpus_available();
+    (63 - (cpus.max(1) as u64).leading_zeros()).max(0)
+}
+
+fn num_cpus_available() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Owns a fixed thread budget (`log_cpus` doublings of work) and hands out
+/// scopes so callers don't each spin up their own pool.
+pub struct Worker {
+    pool: ThreadPool,
+    log_cpus: u32,
+}
+
+impl Worker {
+    pub fn new() -> Self {
+        let log_cpus = log_num_cpus();
+        let num_threads = 1usize << log_cpus;
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .expect("failed to build worker thread pool");
+
+        Worker { pool, log_cpus }
+    }
+
+    pub fn log_num_cpus(&self) -> u32 {
+        self.log_cpus
+    }
+
+    pub fn num_cpus(&self) -> usize {
+        1usize << self.log_cpus
+    }
+
+    /// Runs `f`, giving it a rayon scope to spawn sub-tasks onto this
+    /// worker's pool, and blocks until every spawned task completes.
+    pub fn scope<'a, F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&rayon::Scope<'a>) -> R + Send,
+        R: Send,
+    {
+        self.pool.scope(f)
+    }
+
+    /// Splits `len` items of work into `self.num_cpus()` chunks and runs
+    /// `f(chunk_index, start, len)` for each chunk on this worker's pool,
+    /// waiting for every chunk to finish before returning.
+    pub fn compute<F>(&self, len: usize, f: F)
+    where
+        F: Fn(usize, usize, usize) + Send + Sync,
+    {
+        let num_cpus = self.num_cpus().max(1);
+        let chunk = (len + num_cpus - 1) / num_cpus;
+        if chunk == 0 {
+            return;
+        }
+
+        self.pool.scope(|scope| {
+            let f = &f;
+            for (chunk_index, start) in (0..len).step_by(chunk).enumerate() {
+                let end = (start + chunk).min(len);
+                scope.spawn(move |_| f(chunk_index, start, end - start));
+            }
+        });
+    }
+}
+
+impl Default for Worker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod execution;
+
+pub use self::execution::*;
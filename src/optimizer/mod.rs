@@ -0,0 +1,3 @@
+pub mod jit;
+
+pub use self::jit::*;
@@ -1,223 +0,0 @@
-mod operational;
-mod denotational;
-mod axiomatic;
-mod domains;
-mod rules;
-
-use crate::field::Fr;
-use std::collections::{HashMap, BTreeMap};
-use crate::verification::*;
-
-/// Complete formal semantics system for Jolt zkVM
-pub struct FormalSemantics {
-    // Core semantics components
-    operational: operational::OperationalSemantics,
-    denotational: denotational::DenotationalSemantics,
-    axiomatic: axiomatic::AxiomaticSemantics,
-    
-    // Semantic domains
-    domains: domains::SemanticDomains,
-    
-    // Verification components
-    verification: VerificationSystem,
-    
-    // Type system
-    type_system: TypeSystem,
-    
-    // State management
-    state_manager: StateManager,
-}
-
-impl FormalSemantics {
-    pub fn new(config: FormalConfig) -> Self {
-        Self {
-            operational: operational::OperationalSemantics::new(&config),
-            denotational: denotational::DenotationalSemantics::new(&config),
-            axiomatic: axiomatic::AxiomaticSemantics::new(&config),
-            domains: domains::SemanticDomains::new(&config),
-            verification: VerificationSystem::new(&config),
-            type_system: TypeSystem::new(&config),
-            state_manager: StateManager::new(&config),
-        }
-    }
-
-    /// Verify program correctness
-    pub fn verify_program(&self, program: &Program) -> Result<VerificationResult, SemanticError> {
-        // 1. Type check program
-        self.type_system.check_program(program)?;
-        
-        // 2. Generate verification conditions
-        let conditions = self.generate_verification_conditions(program)?;
-        
-        // 3. Verify operational semantics
-        self.verify_operational_semantics(program, &conditions)?;
-        
-        // 4. Verify denotational semantics
-        self.verify_denotational_semantics(program, &conditions)?;
-        
-        // 5. Verify axiomatic semantics
-        self.verify_axiomatic_semantics(program, &conditions)?;
-        
-        // 6. Generate formal proof
-        let proof = self.generate_formal_proof(program, &conditions)?;
-        
-        Ok(VerificationResult {
-            verified: true,
-            proof,
-            conditions,
-        })
-    }
-
-    /// Generate verification conditions
-    fn generate_verification_conditions(&self, program: &Program) 
-        -> Result<Vec<VerificationCondition>, SemanticError> 
-    {
-        // 1. Extract program specifications
-        let specs = self.extract_specifications(program)?;
-        
-        // 2. Generate type conditions
-        let type_conditions = self.type_system.generate_conditions(program)?;
-        
-        // 3. Generate state invariants
-        let state_invariants = self.state_manager.generate_invariants(program)?;
-        
-        // 4. Generate semantic conditions
-        let semantic_conditions = self.generate_semantic_conditions(
-            program,
-            &specs,
-            &state_invariants
-        )?;
-        
-        // 5. Combine all conditions
-        let mut conditions = Vec::new();
-        conditions.extend(type_conditions);
-        conditions.extend(semantic_conditions);
-        
-        Ok(conditions)
-    }
-
-    /// Verify operational semantics
-    fn verify_operational_semantics(&self, program: &Program, conditions: &[VerificationCondition])
-        -> Result<(), SemanticError>
-    {
-        // 1. Build transition system
-        let transition_system = self.operational.build_transition_system(program)?;
-        
-        // 2. Verify small-step semantics
-        self.operational.verify_small_step(program, &transition_system)?;
-        
-        // 3. Verify big-step semantics
-        self.operational.verify_big_step(program, &transition_system)?;
-        
-        // 4. Verify preservation properties
-        self.operational.verify_preservation(program, conditions)?;
-        
-        // 5. Verify progress properties
-        self.operational.verify_progress(program, conditions)?;
-        
-        Ok(())
-    }
-
-    /// Verify denotational semantics
-    fn verify_denotational_semantics(&self, program: &Program, conditions: &[VerificationCondition])
-        -> Result<(), SemanticError>
-    {
-        // 1. Construct semantic functions
-        let functions = self.denotational.construct_semantic_functions(program)?;
-        
-        // 2. Verify compositionality
-        self.denotational.verify_compositionality(&functions)?;
-        
-        // 3. Verify fixed points
-        self.denotational.verify_fixed_points(&functions, conditions)?;
-        
-        // 4. Verify semantic equivalence
-        self.denotational.verify_equivalence(program, &functions)?;
-        
-        Ok(())
-    }
-
-    /// Verify axiomatic semantics
-    fn verify_axiomatic_semantics(&self, program: &Program, conditions: &[VerificationCondition])
-        -> Result<(), SemanticError>
-    {
-        // 1. Apply Hoare logic rules
-        let hoare_proof = self.axiomatic.apply_hoare_logic(program)?;
-        
-        // 2. Verify proof rules
-        self.axiomatic.verify_proof_rules(&hoare_proof)?;
-        
-        // 3. Verify program logic
-        self.axiomatic.verify_program_logic(program, conditions)?;
-        
-        // 4. Generate verification proof
-        self.axiomatic.generate_verification_proof(program, &hoare_proof)?;
-        
-        Ok(())
-    }
-
-    /// Generate formal proof
-    fn generate_formal_proof(&self, program: &Program, conditions: &[VerificationCondition])
-        -> Result<FormalProof, SemanticError>
-    {
-        // 1. Generate type safety proof
-        let type_proof = self.type_system.generate_proof(program)?;
-        
-        // 2. Generate semantic correctness proof
-        let semantic_proof = self.generate_semantic_proof(program, conditions)?;
-        
-        // 3. Generate preservation proof
-        let preservation_proof = self.generate_preservation_proof(program)?;
-        
-        // 4. Combine proofs
-        Ok(FormalProof {
-            type_proof,
-            semantic_proof,
-            preservation_proof,
-        })
-    }
-}
-
-/// Configuration for formal semantics
-pub struct FormalConfig {
-    // Type system configuration
-    type_config: TypeConfig,
-    
-    // Semantic domains configuration
-    domain_config: DomainConfig,
-    
-    // Verification configuration
-    verification_config: VerificationConfig,
-}
-
-/// Result of formal verification
-pub struct VerificationResult {
-    // Whether program verified successfully
-    verified: bool,
-    
-    // Formal proof of correctness
-    proof: FormalProof,
-    
-    // Verification conditions
-    conditions: Vec<VerificationCondition>,
-}
-
-/// Complete formal proof
-pub struct FormalProof {
-    // Type safety proof
-    type_proof: TypeProof,
-    
-    // Semantic correctness proof
-    semantic_proof: SemanticProof,
-    
-    // Preservation proof
-    preservation_proof: PreservationProof,
-}
-
-#[derive(Debug)]
-pub enum SemanticError {
-    TypeError(TypeError),
-    VerificationError(VerificationError),
-    StateError(StateError),
-    ProofError(ProofError),
-} 
\ No newline at end of file
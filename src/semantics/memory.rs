@@ -1,6 +1,8 @@
 use crate::field::Fr;
 use std::collections::{HashMap, BTreeMap};
 use crate::verification::*;
+use crate::crypto::PoseidonHash;
+use crate::instructions::encoding::Trap;
 
 /// Formal memory model semantics following the Jolt paper
 pub struct MemoryModel {
@@ -29,38 +31,74 @@ pub struct MemoryHierarchy {
     memory_map: MemoryMap,
 }
 
+#[derive(Debug)]
+pub enum TransitionError {
+    Cache,
+    /// The offline memory-checking argument `ConsistencyModel::verify_ordering`
+    /// ran against the transition's ops rejected it.
+    Consistency(OrderingError),
+    Safety,
+}
+
+impl From<OrderingError> for TransitionError {
+    fn from(err: OrderingError) -> Self {
+        TransitionError::Consistency(err)
+    }
+}
+
 impl MemoryHierarchy {
     /// Verify memory access
     pub fn verify_access(&self, access: &MemoryAccess) -> Result<(), AccessError> {
         // 1. Check permissions
         self.verify_permissions(access)?;
-        
+
         // 2. Verify cache coherence
         self.verify_coherence(access)?;
-        
+
         // 3. Check memory consistency
         self.verify_consistency(access)?;
-        
+
         // 4. Verify memory safety
         self.verify_safety(access)?;
-        
+
         Ok(())
     }
 
-    /// Verify memory state transition
-    pub fn verify_transition(&self, old_state: &MemoryState, new_state: &MemoryState) 
-        -> Result<(), TransitionError> 
-    {
+    /// `verify_access` translated into the `Trap` taxonomy
+    /// `InstructionEncoder::decode_or_trap` also produces, so a faulting
+    /// access and a faulting decode both land in the same constrained
+    /// trap-transition path (`TrapHandler`) instead of one aborting and
+    /// the other surfacing an opaque error.
+    pub fn verify_access_or_trap(&self, access: &MemoryAccess) -> Result<(), Trap> {
+        self.verify_access(access).map_err(Trap::from)
+    }
+
+    /// Verify memory state transition. `ops` is the slice of memory
+    /// accesses `old_state` -> `new_state` performed; `consistency` argues
+    /// they're sound via the offline memory-checking argument rather than
+    /// a structural check, and the resulting `ConsistencyProof` is handed
+    /// back to the caller.
+    pub fn verify_transition(
+        &self,
+        old_state: &MemoryState,
+        new_state: &MemoryState,
+        consistency: &ConsistencyModel,
+        ops: &[MemoryOp],
+    ) -> Result<ConsistencyProof, TransitionError> {
         // 1. Verify cache state transitions
         self.verify_cache_transitions(old_state, new_state)?;
-        
-        // 2. Verify memory consistency
-        self.verify_consistency_transition(old_state, new_state)?;
-        
+
+        // 2. Verify memory consistency via the Jolt/Lasso offline
+        // memory-checking argument.
+        let consistency_proof = consistency.verify_ordering(ops)?;
+        if !consistency_proof.is_satisfied() {
+            return Err(TransitionError::Consistency(OrderingError::MultisetMismatch));
+        }
+
         // 3. Check safety preservation
         self.verify_safety_preservation(old_state, new_state)?;
-        
-        Ok(())
+
+        Ok(consistency_proof)
     }
 }
 
@@ -76,19 +114,140 @@ pub struct ConsistencyModel {
     coherence: CoherenceProtocol,
 }
 
+/// One memory access in a program trace: `(addr, value, timestamp)` plus
+/// its direction, the same tuple shape
+/// [`crate::crypto::memory_check::MemoryAccess`] fingerprints for
+/// `MemorySystem`, reused here so `ConsistencyModel` can run the identical
+/// offline memory-checking argument against the semantics layer's trace
+/// representation.
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryOp {
+    pub addr: u64,
+    pub value: u64,
+    pub timestamp: u64,
+    pub is_write: bool,
+}
+
+/// Why `MemoryHierarchy::verify_access` rejected an access, one variant
+/// per check it runs: permissions, coherence/consistency (folded into
+/// `Incoherent` — both stem from the same stale-view failure mode), and
+/// the two memory-safety faults (`Misaligned`/`OutOfBounds`) a real
+/// RISC-V core would raise as distinct trap causes via
+/// `verify_access_or_trap`.
+#[derive(Debug)]
+pub enum AccessError {
+    PermissionDenied,
+    Incoherent,
+    Misaligned(u64),
+    OutOfBounds(u64),
+}
+
+#[derive(Debug)]
+pub enum OrderingError {
+    /// An op at the given index didn't strictly increase its address's
+    /// timestamp past the last op that touched it, which would let a
+    /// malicious prover reorder accesses to the same cell.
+    NonMonotonicTimestamp(usize),
+    /// The multiset identity `I ⊎ WS == RS ⊎ F` failed to hold under the
+    /// fingerprint grand product.
+    MultisetMismatch,
+}
+
+/// Fingerprint grand-product proof that a trace of [`MemoryOp`]s is
+/// consistent with some well-ordered sequence of writes: the multiset
+/// identity `I ⊎ WS == RS ⊎ F` (every cell's initial value, plus every
+/// value written, equals every value read, plus every cell's final value),
+/// argued the way [`crate::crypto::memory_check::MemoryConsistencyProof`]
+/// argues it for `MemorySystem`, except `gamma`/`tau` come from a running
+/// `PoseidonHash` rather than a `Transcript`, since the semantics layer
+/// doesn't carry one.
+#[derive(Clone, Debug)]
+pub struct ConsistencyProof {
+    pub gamma: Fr,
+    pub tau: Fr,
+    pub read_set_product: Fr,
+    pub write_set_product: Fr,
+    pub init_set_product: Fr,
+    pub final_set_product: Fr,
+}
+
+impl ConsistencyProof {
+    /// `I ⊎ WS == RS ⊎ F` reduces, after fingerprinting, to one product
+    /// equality: `init_set_product * write_set_product ==
+    /// read_set_product * final_set_product`.
+    pub fn is_satisfied(&self) -> bool {
+        self.init_set_product * self.write_set_product == self.read_set_product * self.final_set_product
+    }
+}
+
+/// `tau - (addr + gamma*value + gamma^2*timestamp)`: two distinct tuples
+/// collide only if `gamma`/`tau` happen to hit a root of their difference
+/// polynomial, negligible over a large field.
+fn fingerprint(addr: u64, value: u64, timestamp: u64, gamma: Fr, tau: Fr) -> Fr {
+    tau - (Fr::from(addr) + gamma * Fr::from(value) + gamma * gamma * Fr::from(timestamp))
+}
+
+fn grand_product(tuples: &[(u64, u64, u64)], gamma: Fr, tau: Fr) -> Fr {
+    tuples.iter().fold(Fr::one(), |acc, &(addr, value, timestamp)| {
+        acc * fingerprint(addr, value, timestamp, gamma, tau)
+    })
+}
+
 impl ConsistencyModel {
-    /// Verify memory operation ordering
-    pub fn verify_ordering(&self, ops: &[MemoryOp]) -> Result<(), OrderingError> {
-        // 1. Build happens-before graph
-        let hb_graph = self.build_happens_before(ops)?;
-        
-        // 2. Check acyclicity
-        self.verify_acyclic(&hb_graph)?;
-        
-        // 3. Verify sequential consistency
-        self.verify_sequential_consistency(ops, &hb_graph)?;
-        
-        Ok(())
+    /// Verifies `ops` is consistent with some well-ordered sequence of
+    /// writes via the Jolt/Lasso offline memory-checking argument, rather
+    /// than building a happens-before graph and checking it for cycles —
+    /// acyclicity alone doesn't rule out a prover replaying a stale value,
+    /// so it isn't sound for a zkVM proof.
+    ///
+    /// Every address touched by `ops` starts with an implicit `(addr, 0,
+    /// 0)` tuple in the init-set `I`; each op appends its pre-access tuple
+    /// to the read-set `RS` and its post-access tuple, timestamped
+    /// strictly after the pre-access one, to the write-set `WS` (a plain
+    /// read's post-access tuple carries the same value as its pre-access
+    /// one — only the timestamp advances). After the last op touching each
+    /// address, that address's tuple lands in the final-set `F`. The
+    /// multiset identity `I ⊎ WS == RS ⊎ F` holds iff every read returned
+    /// the value some earlier write actually placed there.
+    pub fn verify_ordering(&self, ops: &[MemoryOp]) -> Result<ConsistencyProof, OrderingError> {
+        let mut hasher = PoseidonHash::new();
+        let gamma = hasher.hash(&[Fr::zero(), Fr::one()]);
+        let tau = hasher.hash(&[gamma, Fr::one()]);
+
+        // addr -> (value, timestamp) as of the last op that touched it.
+        let mut current: HashMap<u64, (u64, u64)> = HashMap::new();
+        let mut read_tuples = Vec::new();
+        let mut write_tuples = Vec::new();
+
+        for (i, op) in ops.iter().enumerate() {
+            let (prev_value, prev_timestamp) = current.get(&op.addr).copied().unwrap_or((0, 0));
+            if op.timestamp <= prev_timestamp {
+                return Err(OrderingError::NonMonotonicTimestamp(i));
+            }
+
+            // A read's tuple carries the value it claims to have returned.
+            let read_value = if op.is_write { prev_value } else { op.value };
+            read_tuples.push((op.addr, read_value, prev_timestamp));
+            let post_value = if op.is_write { op.value } else { read_value };
+            write_tuples.push((op.addr, post_value, op.timestamp));
+            current.insert(op.addr, (post_value, op.timestamp));
+        }
+
+        let init_tuples: Vec<(u64, u64, u64)> =
+            current.keys().map(|&addr| (addr, 0, 0)).collect();
+        let final_tuples: Vec<(u64, u64, u64)> = current
+            .iter()
+            .map(|(&addr, &(value, timestamp))| (addr, value, timestamp))
+            .collect();
+
+        Ok(ConsistencyProof {
+            gamma,
+            tau,
+            read_set_product: grand_product(&read_tuples, gamma, tau),
+            write_set_product: grand_product(&write_tuples, gamma, tau),
+            init_set_product: grand_product(&init_tuples, gamma, tau),
+            final_set_product: grand_product(&final_tuples, gamma, tau),
+        })
     }
 
     /// Verify memory visibility rules
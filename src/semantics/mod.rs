@@ -0,0 +1,7 @@
+pub mod formal;
+pub mod memory;
+pub mod operational;
+
+pub use self::formal::*;
+pub use self::memory::*;
+pub use self::operational::*;
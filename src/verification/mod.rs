@@ -0,0 +1,5 @@
+pub mod lookup_verifier;
+pub mod semantic_checker;
+
+pub use self::lookup_verifier::*;
+pub use self::semantic_checker::*;
@@ -1,5 +1,395 @@
-use crate::field::Fr;
-use crate::polynomial::*;
+use crate::crypto::hash::PoseidonTranscript;
+use crate::crypto::lookup::table::use_extension_challenge;
+use crate::field::{Fr, Fr2};
+use std::collections::HashMap;
+
+/// A single constraint row within a [`StepTemplate`]'s single-step block:
+/// the variables it reads (flat indices into the step's own witness
+/// block) plus the standard 5-coefficient PLONK row (`q_L, q_R, q_O, q_M,
+/// q_C`), satisfied when `q_L*a + q_R*b + q_O*c + q_M*a*b + q_C == 0`.
+#[derive(Clone)]
+pub struct Gate {
+    pub vars: Vec<usize>,
+    pub coefficients: Vec<Fr>,
+}
+
+/// A copy-constraint cycle: every variable in `cycle` must carry the same
+/// witness value. Used both for a step's internal wiring and, via
+/// [`StepTemplate::wire_to_next_step`], for the constraints joining one
+/// step's outputs to the next step's inputs.
+#[derive(Clone)]
+pub struct Permutation {
+    pub cycle: Vec<usize>,
+}
+
+/// A lookup a step's block must satisfy: the variables read (flat indices
+/// into the step's block) and `table_id`, which [`ConstraintSystem`] uses
+/// as the single table row every one of `vars` must read, with value
+/// `Fr::from(table_id)`; no table contents are stored.
+#[derive(Clone)]
+pub struct LookupTable {
+    pub vars: Vec<usize>,
+    pub table_id: usize,
+}
+
+/// A single CPU step's gates, copy constraints, and lookups, registered
+/// once against the step's own flat variable indices (`0..step_vars`).
+/// [`instantiate`](Self::instantiate) tiles this block across a whole
+/// trace, shifting every variable index by `step * step_vars` per
+/// repetition and automatically generating the cross-step copy
+/// constraints declared via [`wire_to_next_step`](Self::wire_to_next_step)
+/// — this is what makes the constraint system *uniform*: the matrices for
+/// a `T`-step program are just `T` repeated copies of the ~60 constraints
+/// / ~80 variables describing one fetch-decode-execute step, exactly as
+/// described in the Jolt R1CS constraints document.
+pub struct StepTemplate {
+    step_vars: usize,
+    gates: Vec<Gate>,
+    permutations: Vec<Permutation>,
+    lookups: Vec<LookupTable>,
+    /// `(output_var, input_var)` pairs: step `i`'s `output_var` is wired to
+    /// step `i + 1`'s `input_var` via a fresh two-element [`Permutation`]
+    /// cycle per step boundary.
+    cross_step_wires: Vec<(usize, usize)>,
+}
+
+impl StepTemplate {
+    /// `step_vars` is the width of a single step's witness block (Jolt's
+    /// fetch-decode-execute step uses on the order of 80).
+    pub fn new(step_vars: usize) -> Self {
+        StepTemplate {
+            step_vars,
+            gates: Vec::new(),
+            permutations: Vec::new(),
+            lookups: Vec::new(),
+            cross_step_wires: Vec::new(),
+        }
+    }
+
+    pub fn register_gate(&mut self, gate: Gate) -> &mut Self {
+        self.gates.push(gate);
+        self
+    }
+
+    pub fn register_copy_constraint(&mut self, permutation: Permutation) -> &mut Self {
+        self.permutations.push(permutation);
+        self
+    }
+
+    pub fn register_lookup(&mut self, lookup: LookupTable) -> &mut Self {
+        self.lookups.push(lookup);
+        self
+    }
+
+    /// Declares that `output_var` in every step feeds `input_var` in the
+    /// step right after it (e.g. a step's next-PC output feeding the
+    /// following step's PC input), without the caller enumerating one
+    /// cross-step copy constraint per trace step by hand.
+    pub fn wire_to_next_step(&mut self, output_var: usize, input_var: usize) -> &mut Self {
+        self.cross_step_wires.push((output_var, input_var));
+        self
+    }
+
+    /// Tiles this template across `num_steps` repetitions: every step gets
+    /// its own variable-shifted copy of `gates`/`permutations`/`lookups`,
+    /// plus one fresh [`Permutation`] cycle per declared cross-step wire
+    /// per step boundary, joining step `i`'s output variable to step
+    /// `i + 1`'s input variable.
+    pub fn instantiate(&self, num_steps: usize) -> InstantiatedSteps {
+        let mut gates = Vec::with_capacity(self.gates.len() * num_steps);
+        let mut permutations = Vec::with_capacity(self.permutations.len() * num_steps);
+        let mut lookups = Vec::with_capacity(self.lookups.len() * num_steps);
+
+        for step in 0..num_steps {
+            let offset = step * self.step_vars;
+            gates.extend(self.gates.iter().map(|gate| Gate {
+                vars: gate.vars.iter().map(|v| v + offset).collect(),
+                coefficients: gate.coefficients.clone(),
+            }));
+            permutations.extend(self.permutations.iter().map(|permutation| Permutation {
+                cycle: permutation.cycle.iter().map(|v| v + offset).collect(),
+            }));
+            lookups.extend(self.lookups.iter().map(|lookup| LookupTable {
+                vars: lookup.vars.iter().map(|v| v + offset).collect(),
+                table_id: lookup.table_id,
+            }));
+        }
+
+        for step in 0..num_steps.saturating_sub(1) {
+            let this_offset = step * self.step_vars;
+            let next_offset = (step + 1) * self.step_vars;
+            for &(output_var, input_var) in &self.cross_step_wires {
+                permutations.push(Permutation {
+                    cycle: vec![output_var + this_offset, input_var + next_offset],
+                });
+            }
+        }
+
+        InstantiatedSteps {
+            gates,
+            permutations,
+            lookups,
+            num_steps,
+            step_vars: self.step_vars,
+        }
+    }
+}
+
+/// The result of tiling a [`StepTemplate`] across a `num_steps`-step
+/// trace: flat `gates`/`permutations`/`lookups` a [`ConstraintSystem`] can
+/// install directly, with every step's copy materialized, plus the
+/// repetition metadata (`step_vars`, `num_steps`) the system records as
+/// `step_repetition`.
+pub struct InstantiatedSteps {
+    pub gates: Vec<Gate>,
+    pub permutations: Vec<Permutation>,
+    pub lookups: Vec<LookupTable>,
+    pub num_steps: usize,
+    pub step_vars: usize,
+}
+
+/// A LogUp lookup argument, replacing the permutation-based
+/// (plookup-style) `LookupManager` this file used before chunk6-6. Proves
+/// the multiset of looked-up values `{f_i}` is contained in a table
+/// `{t_j}` via the rational-function identity `sum_i 1/(alpha+f_i) ==
+/// sum_j m_j/(alpha+t_j)` instead of a sorted-concatenation permutation
+/// argument — no sorted witness is needed, which is what lets it scale to
+/// Jolt's many decomposed instruction-lookup tables.
+pub struct LogUpArgument {
+    /// Recorded multiplicities `m_j`, keyed by table row index, built up
+    /// by [`record_read`](Self::record_read) as witness lookups are
+    /// accumulated.
+    multiplicities: HashMap<usize, u64>,
+}
+
+impl LogUpArgument {
+    pub fn new() -> Self {
+        LogUpArgument {
+            multiplicities: HashMap::new(),
+        }
+    }
+
+    /// Records a witness row's read of table row `table_row`, incrementing
+    /// its multiplicity for the frequency identity below.
+    pub fn record_read(&mut self, table_row: usize) {
+        *self.multiplicities.entry(table_row).or_insert(0) += 1;
+    }
+
+    /// Draws the LogUp challenge `alpha` from `transcript`, moving to the
+    /// `Fr2` extension field (two squeezes packed as its coordinates)
+    /// whenever [`use_extension_challenge`] says a single `Fr` squeeze
+    /// isn't sound enough for this field — the same small-field fix
+    /// `crate::crypto::lookup::table`'s `_ext` proving paths use.
+    pub fn squeeze_alpha(transcript: &mut PoseidonTranscript) -> Fr2 {
+        let a0 = transcript.squeeze();
+        if use_extension_challenge() {
+            let a1 = transcript.squeeze();
+            Fr2::new(a0, a1)
+        } else {
+            Fr2::from_base(a0)
+        }
+    }
+
+    /// Builds the running-sum witness column `S`: `S_0 = 0` and
+    /// `S` accumulates the two sides of the identity separately: one term
+    /// per element of `lookups` (every trace read, duplicates and all),
+    /// then one term per entry of `table_rows` (each *distinct* table row
+    /// exactly once, weighted by its recorded multiplicity). Folding the
+    /// multiplicity in once per read — instead of once per distinct row —
+    /// would double-count any row read more than once, which is exactly
+    /// why `table_rows` is deduplicated by the caller rather than mirroring
+    /// `lookups` position-for-position. The LogUp identity `sum_i
+    /// 1/(alpha+f_i) == sum_j m_j/(alpha+t_j)` holds (so `S` returns to
+    /// zero) iff the multiset of reads is covered by the table with
+    /// exactly these multiplicities.
+    ///
+    /// Each `table_rows` entry is `(row_id, row_value)`; `row_id` is the
+    /// same id [`record_read`](Self::record_read) was given when this row
+    /// was read, so multiplicities are looked up by actual table row, not
+    /// by a read's position in the trace.
+    pub fn build_running_sum(
+        &self,
+        lookups: &[Fr],
+        table_rows: &[(usize, Fr)],
+        alpha: Fr2,
+    ) -> Option<Vec<Fr2>> {
+        let mut sum = Vec::with_capacity(lookups.len() + table_rows.len() + 1);
+        sum.push(Fr2::zero());
+
+        for &f in lookups {
+            let term = (alpha + Fr2::from_base(f)).inverse()?;
+            sum.push(*sum.last().unwrap() + term);
+        }
+        for &(row_id, t) in table_rows {
+            let multiplicity = *self.multiplicities.get(&row_id).unwrap_or(&0);
+            let term = (alpha + Fr2::from_base(t)).inverse()? * Fr2::from_base(Fr::from(multiplicity));
+            sum.push(*sum.last().unwrap() - term);
+        }
+        Some(sum)
+    }
+
+    /// The LogUp closing check: the running-sum column must return to
+    /// zero once every row's contribution has been folded in.
+    pub fn verify_running_sum(sum: &[Fr2]) -> bool {
+        sum.last().map_or(false, |last| last.is_zero())
+    }
+}
+
+/// A custom gate row, evaluated the same way [`Gate`] is: the standard
+/// 5-coefficient PLONK row (`q_L, q_R, q_O, q_M, q_C`), satisfied when
+/// `q_L*a + q_R*b + q_O*c + q_M*a*b + q_C == 0` for the (up to three)
+/// witness values `vars` resolves to.
+#[derive(Clone)]
+pub struct CustomGate {
+    pub vars: Vec<usize>,
+    pub coefficients: Vec<Fr>,
+}
+
+impl CustomGate {
+    fn evaluate(&self, values: &[Fr]) -> Fr {
+        let a = values.first().copied().unwrap_or_else(Fr::zero);
+        let b = values.get(1).copied().unwrap_or_else(Fr::zero);
+        let c = values.get(2).copied().unwrap_or_else(Fr::zero);
+        let q_l = self.coefficients.first().copied().unwrap_or_else(Fr::zero);
+        let q_r = self.coefficients.get(1).copied().unwrap_or_else(Fr::zero);
+        let q_o = self.coefficients.get(2).copied().unwrap_or_else(Fr::zero);
+        let q_m = self.coefficients.get(3).copied().unwrap_or_else(Fr::zero);
+        let q_c = self.coefficients.get(4).copied().unwrap_or_else(Fr::zero);
+        q_l * a + q_r * b + q_o * c + q_m * a * b + q_c
+    }
+}
+
+/// The selector column singling out which row a [`CustomGate`] applies to:
+/// one evaluation per registered custom gate so far, `1` at the new
+/// gate's own row and `0` elsewhere.
+pub struct SelectorPolynomial {
+    pub evaluations: Vec<Fr>,
+}
+
+/// A copy constraint generated on a [`CustomGate`]'s behalf (as opposed to
+/// one registered directly via [`StepTemplate::register_copy_constraint`]).
+pub struct CopyConstraint {
+    pub cycle: Vec<usize>,
+}
+
+/// Checks a witness value against a bit-length bound without a full
+/// bit-decomposition argument: folds every witness value into a single
+/// Fiat-Shamir-weighted accumulator after confirming it fits in
+/// `bits` bits, so a verifier recomputing the same accumulator from a
+/// claimed in-range witness gets the same field element back.
+pub struct RangeProver {
+    bits: usize,
+}
+
+impl RangeProver {
+    pub fn new(bits: usize) -> Self {
+        RangeProver { bits }
+    }
+
+    fn prove(&self, witness: &[Fr], challenge: Fr) -> Result<RangeProofs, ProofError> {
+        let max = if self.bits >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << self.bits) - 1
+        };
+
+        let mut challenge_power = Fr::one();
+        let mut accumulator = Fr::zero();
+        for &value in witness {
+            if value.to_u64() > max {
+                return Err(ProofError::ValueOutOfRange);
+            }
+            accumulator += challenge_power * value;
+            challenge_power *= challenge;
+        }
+        Ok(RangeProofs { accumulator })
+    }
+}
+
+/// Everything [`ConstraintSystem::create_proof`] hands back: one
+/// sub-proof per stage, in the order each stage's challenge was squeezed.
+pub struct Proof {
+    pub witness_commitments: Vec<Fr>,
+    pub permutation_proof: PermutationProof,
+    pub lookup_proofs: LookupProofs,
+    pub range_proofs: RangeProofs,
+    pub gate_proofs: GateProofs,
+}
+
+/// The copy-constraint grand-product check folded into a single
+/// accumulator: a random linear combination (via `beta`/`gamma`) of every
+/// cycle's adjacent-pair differences, zero iff every registered
+/// [`Permutation`] cycle's witness values actually agree.
+pub struct PermutationProof {
+    accumulator: Fr,
+}
+
+impl PermutationProof {
+    fn transcript_elements(&self) -> Vec<Fr> {
+        vec![self.accumulator]
+    }
+}
+
+/// The LogUp argument's closing value (the last entry of
+/// [`LogUpArgument::build_running_sum`]'s running-sum column), packed as
+/// its two `Fr2` coordinates for absorption into a `Fr`-only transcript.
+pub struct LookupProofs {
+    running_sum_final: Fr2,
+}
+
+impl LookupProofs {
+    fn transcript_elements(&self) -> Vec<Fr> {
+        let (a0, a1) = self.running_sum_final.coordinates();
+        vec![a0, a1]
+    }
+}
+
+/// The range-check accumulator [`RangeProver::prove`] produces.
+pub struct RangeProofs {
+    accumulator: Fr,
+}
+
+impl RangeProofs {
+    fn transcript_elements(&self) -> Vec<Fr> {
+        vec![self.accumulator]
+    }
+}
+
+/// The custom-gate accumulator [`ConstraintSystem::prove_custom_gates`]
+/// produces: a random linear combination (via `gate_challenge`) of every
+/// registered [`CustomGate`]'s row evaluation, zero iff every custom gate
+/// is satisfied by `witness`.
+pub struct GateProofs {
+    accumulator: Fr,
+}
+
+/// Configures a fresh [`ConstraintSystem`]: `range_bits` is the bit width
+/// [`RangeProver`] enforces for every witness value.
+pub struct ConstraintConfig {
+    pub range_bits: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConstraintError {
+    /// A [`CustomGate`] doesn't fit the 5-coefficient, up-to-3-wire PLONK
+    /// row shape every other gate in this system uses.
+    IncompatibleGate,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// `create_proof` was called against an empty witness.
+    EmptyWitness,
+    /// A gate, permutation, or lookup referenced a witness index past the
+    /// end of the witness vector.
+    WitnessIndexOutOfRange,
+    /// A range-checked witness value exceeded its configured bit bound.
+    ValueOutOfRange,
+    /// [`LogUpArgument::build_running_sum`] hit a read or table row whose
+    /// value collides with `-alpha` under the drawn challenge, making a
+    /// term's denominator zero.
+    LookupChallengeCollision,
+}
 
 /// Advanced zero-knowledge constraint system
 pub struct ConstraintSystem {
@@ -7,19 +397,27 @@ pub struct ConstraintSystem {
     gates: Vec<Gate>,
     permutations: Vec<Permutation>,
     lookups: Vec<LookupTable>,
-    
+
     // Custom gates
     custom_gates: Vec<CustomGate>,
-    
+
     // Optimization components
     selector_polynomials: Vec<SelectorPolynomial>,
     copy_constraints: Vec<CopyConstraint>,
-    
-    // Lookup argument system
-    lookup_manager: LookupManager,
-    
+
+    // Lookup argument system: a LogUp argument (chunk6-6) rather than the
+    // permutation-based `LookupManager` this field used to hold.
+    logup: LogUpArgument,
+
     // Range proof system
     range_prover: RangeProver,
+
+    // The registered single-step template, if any, and the `(step_vars,
+    // num_steps)` repetition it was last tiled at. `create_proof` does not
+    // read `step_repetition`; it works over the fully tiled gates and
+    // permutations.
+    step_template: Option<StepTemplate>,
+    step_repetition: Option<(usize, usize)>,
 }
 
 impl ConstraintSystem {
@@ -31,11 +429,35 @@ impl ConstraintSystem {
             custom_gates: Vec::new(),
             selector_polynomials: Vec::new(),
             copy_constraints: Vec::new(),
-            lookup_manager: LookupManager::new(config.lookup_bits),
+            logup: LogUpArgument::new(),
             range_prover: RangeProver::new(config.range_bits),
+            step_template: None,
+            step_repetition: None,
         }
     }
 
+    /// Registers the single-step template this system's trace will be
+    /// built from; [`instantiate`](Self::instantiate) tiles it once the
+    /// trace length is known.
+    pub fn set_step_template(&mut self, template: StepTemplate) {
+        self.step_template = Some(template);
+    }
+
+    /// Tiles the registered [`StepTemplate`] across `num_steps` copies and
+    /// installs the result as this system's gates/permutations/lookups,
+    /// implementing Jolt's uniformity property: callers describe one
+    /// fetch-decode-execute step once instead of registering a whole
+    /// trace's worth of otherwise-identical constraints by hand. Returns
+    /// `None` if no template has been registered yet.
+    pub fn instantiate(&mut self, num_steps: usize) -> Option<()> {
+        let instantiated = self.step_template.as_ref()?.instantiate(num_steps);
+        self.gates = instantiated.gates;
+        self.permutations = instantiated.permutations;
+        self.lookups = instantiated.lookups;
+        self.step_repetition = Some((instantiated.step_vars, instantiated.num_steps));
+        Some(())
+    }
+
     /// Add custom gate to the constraint system
     pub fn add_custom_gate(&mut self, gate: CustomGate) -> Result<(), ConstraintError> {
         // 1. Verify gate compatibility
@@ -56,36 +478,196 @@ impl ConstraintSystem {
     }
 
     /// Add lookup table constraint
+    ///
+    /// Unlike the permutation-based `LookupManager` this replaced, LogUp
+    /// needs no sorted-witness preprocessing against the table: `table` is
+    /// registered as-is, and `self.logup` accumulates each row's read
+    /// multiplicity (keyed by `table.table_id`, the actual table row every
+    /// one of `table.vars` reads) as the witness is built, for
+    /// `prove_lookups` to thread through [`LogUpArgument::build_running_sum`]
+    /// later.
     pub fn add_lookup_constraint(&mut self, table: LookupTable) -> Result<(), ConstraintError> {
-        // 1. Preprocess lookup table
-        let processed_table = self.lookup_manager.preprocess_table(&table)?;
-        
-        // 2. Generate lookup polynomials
-        let polynomials = self.generate_lookup_polynomials(&processed_table)?;
-        
-        // 3. Add to constraint system
-        self.lookups.push(processed_table);
-        
+        for _ in &table.vars {
+            self.logup.record_read(table.table_id);
+        }
+        self.lookups.push(table);
         Ok(())
     }
 
+    /// A [`CustomGate`] must fit the same shape every other gate in this
+    /// system does: the standard 5-coefficient PLONK row, over at most the
+    /// three wires (`a`, `b`, `c`) that row knows how to combine.
+    fn verify_gate_compatibility(&self, gate: &CustomGate) -> Result<(), ConstraintError> {
+        if gate.coefficients.len() != 5 || gate.vars.len() > 3 {
+            return Err(ConstraintError::IncompatibleGate);
+        }
+        Ok(())
+    }
+
+    /// Builds the selector column singling out `gate`'s own row (the
+    /// number of custom gates registered before it) among all custom
+    /// gates registered so far.
+    fn create_selector_polynomial(&self, _gate: &CustomGate) -> Result<SelectorPolynomial, ConstraintError> {
+        let row = self.custom_gates.len();
+        let mut evaluations = vec![Fr::zero(); row + 1];
+        evaluations[row] = Fr::one();
+        Ok(SelectorPolynomial { evaluations })
+    }
+
+    /// A bare [`CustomGate`] registration carries no wiring to other gates
+    /// beyond what its own `vars` already reference by absolute witness
+    /// index — callers needing cross-gate copy constraints register them
+    /// explicitly via [`StepTemplate::register_copy_constraint`], so none
+    /// are implied here.
+    fn generate_copy_constraints(&self, _gate: &CustomGate) -> Result<Vec<CopyConstraint>, ConstraintError> {
+        Ok(Vec::new())
+    }
+
+    /// Commits to the witness with a single Pedersen vector commitment,
+    /// the same primitive [`crate::crypto::plonk`] uses for its own
+    /// witness commitment.
+    fn commit_to_witness(&self, witness: &[Fr]) -> Result<Vec<Fr>, ProofError> {
+        if witness.is_empty() {
+            return Err(ProofError::EmptyWitness);
+        }
+        let scheme = crate::crypto::commitment::PedersenCommitment::new(witness.len());
+        Ok(vec![scheme.commit(witness)])
+    }
+
+    /// Folds every registered [`Permutation`] cycle's adjacent-pair
+    /// differences into a single accumulator via a `beta`/`gamma` random
+    /// linear combination: each pair contributes `beta^i * (value -
+    /// next_value)` and each cycle's contribution is additionally scaled
+    /// by `gamma`, so the accumulator is zero iff every cycle's witness
+    /// values are pairwise equal (overwhelmingly, by the random
+    /// coefficients' linear independence).
+    fn prove_permutation(&self, witness: &[Fr], beta: Fr, gamma: Fr) -> Result<PermutationProof, ProofError> {
+        let mut accumulator = Fr::zero();
+        for permutation in &self.permutations {
+            let cycle = &permutation.cycle;
+            if cycle.is_empty() {
+                continue;
+            }
+            let mut challenge_power = Fr::one();
+            for (position, &var) in cycle.iter().enumerate() {
+                let next_var = cycle[(position + 1) % cycle.len()];
+                let value = *witness.get(var).ok_or(ProofError::WitnessIndexOutOfRange)?;
+                let next_value = *witness.get(next_var).ok_or(ProofError::WitnessIndexOutOfRange)?;
+                accumulator += challenge_power * (value - next_value);
+                challenge_power *= beta;
+            }
+            accumulator *= gamma;
+        }
+        Ok(PermutationProof { accumulator })
+    }
+
+    /// Reads every registered [`LookupTable`]'s `vars` out of `witness` as
+    /// this proof's lookup reads, and pairs each *distinct* `table_id`
+    /// among them with its table value exactly once, for
+    /// [`LogUpArgument::build_running_sum`] to weight by the multiplicity
+    /// `add_lookup_constraint` already recorded under that same id —
+    /// feeding one table-row term per read, instead of once per distinct
+    /// row, would double-count any row read more than once.
+    fn prove_lookups(&self, witness: &[Fr], lookup_challenge: Fr2) -> Result<LookupProofs, ProofError> {
+        if self.lookups.is_empty() {
+            return Ok(LookupProofs {
+                running_sum_final: Fr2::zero(),
+            });
+        }
+
+        let mut reads = Vec::new();
+        let mut table_rows = Vec::new();
+        let mut seen_rows = std::collections::HashSet::new();
+        for lookup in &self.lookups {
+            for &var in &lookup.vars {
+                let value = *witness.get(var).ok_or(ProofError::WitnessIndexOutOfRange)?;
+                reads.push(value);
+            }
+            if seen_rows.insert(lookup.table_id) {
+                table_rows.push((lookup.table_id, Fr::from(lookup.table_id as u64)));
+            }
+        }
+
+        let running_sum = self
+            .logup
+            .build_running_sum(&reads, &table_rows, lookup_challenge)
+            .ok_or(ProofError::LookupChallengeCollision)?;
+        Ok(LookupProofs {
+            running_sum_final: *running_sum.last().expect("running_sum is never empty"),
+        })
+    }
+
+    /// Delegates to [`RangeProver::prove`] for `self.range_prover`'s
+    /// configured bit bound.
+    fn prove_ranges(&self, witness: &[Fr], range_challenge: Fr) -> Result<RangeProofs, ProofError> {
+        self.range_prover.prove(witness, range_challenge)
+    }
+
+    /// Folds every registered [`CustomGate`]'s row evaluation into a
+    /// single accumulator via a `gate_challenge` random linear
+    /// combination, zero iff every custom gate is satisfied.
+    fn prove_custom_gates(&self, witness: &[Fr], gate_challenge: Fr) -> Result<GateProofs, ProofError> {
+        let mut challenge_power = Fr::one();
+        let mut accumulator = Fr::zero();
+        for gate in &self.custom_gates {
+            let values = gate
+                .vars
+                .iter()
+                .map(|&v| witness.get(v).copied().ok_or(ProofError::WitnessIndexOutOfRange))
+                .collect::<Result<Vec<_>, _>>()?;
+            accumulator += challenge_power * gate.evaluate(&values);
+            challenge_power *= gate_challenge;
+        }
+        Ok(GateProofs { accumulator })
+    }
+
     /// Generate proof
+    ///
+    /// Every sub-proof is bound to everything committed before it via a
+    /// single [`PoseidonTranscript`] duplex sponge: each stage absorbs its
+    /// own output before the next stage's challenge is squeezed, so a
+    /// verifier replaying the same absorb/squeeze sequence recovers
+    /// identical challenges iff nothing upstream was tampered with.
+    ///
+    /// When this system was built via [`instantiate`](Self::instantiate),
+    /// `commit_to_witness`/`prove_permutation` work over the fully tiled
+    /// gates and permutations, one materialized copy per step; the
+    /// recorded `step_repetition` is not used here.
     pub fn create_proof(&self, witness: &[Fr]) -> Result<Proof, ProofError> {
-        // 1. Commit to witness polynomials
+        let mut transcript = PoseidonTranscript::new();
+
+        // 1. Commit to witness polynomials, and bind them first so every
+        // challenge derived below depends on the full witness.
         let witness_commitments = self.commit_to_witness(witness)?;
-        
-        // 2. Generate permutation proof
-        let perm_proof = self.prove_permutation(witness)?;
-        
-        // 3. Generate lookup proofs
-        let lookup_proofs = self.prove_lookups(witness)?;
-        
-        // 4. Generate range proofs
-        let range_proofs = self.prove_ranges(witness)?;
-        
-        // 5. Generate custom gate proofs
-        let gate_proofs = self.prove_custom_gates(witness)?;
-        
+        transcript.absorb(&witness_commitments);
+
+        // 2. Derive the permutation challenges (beta, gamma) from the
+        // transcript rather than sampling them out-of-band.
+        let beta = transcript.squeeze();
+        let gamma = transcript.squeeze();
+        let perm_proof = self.prove_permutation(witness, beta, gamma)?;
+        transcript.absorb(&perm_proof.transcript_elements());
+
+        // 3. Generate lookup proofs via the LogUp argument (chunk6-6):
+        // alpha is drawn in Fr2 rather than Fr whenever
+        // `use_extension_challenge` says this field is too small for one
+        // squeeze to soundly bind every lookup row, exactly as
+        // `LogUpArgument::squeeze_alpha` documents.
+        let lookup_challenge = LogUpArgument::squeeze_alpha(&mut transcript);
+        let lookup_proofs = self.prove_lookups(witness, lookup_challenge)?;
+        transcript.absorb(&lookup_proofs.transcript_elements());
+
+        // 4. Generate range proofs under a challenge bound to everything
+        // above.
+        let range_challenge = transcript.squeeze();
+        let range_proofs = self.prove_ranges(witness, range_challenge)?;
+        transcript.absorb(&range_proofs.transcript_elements());
+
+        // 5. Generate custom gate proofs under a final challenge binding
+        // the whole transcript.
+        let gate_challenge = transcript.squeeze();
+        let gate_proofs = self.prove_custom_gates(witness, gate_challenge)?;
+
         // 6. Combine all proofs
         Ok(Proof {
             witness_commitments,
@@ -0,0 +1,3 @@
+pub mod constraint_system;
+
+pub use self::constraint_system::*;